@@ -0,0 +1,34 @@
+//! Benchmarks for every day's solution.
+//!
+//! Run with `cargo bench`. Each day is timed against its real input,
+//! found the same way `advent2024 run` finds it, and days without an
+//! input are skipped. Set `BENCH_ITERATIONS` to change how many times
+//! each step is run.
+
+use std::env;
+
+use advent2024::days::DAYS;
+use advent2024::input::InputSource;
+
+/// How many times each step is run, unless `BENCH_ITERATIONS` says otherwise.
+const ITERATIONS: usize = 100;
+
+fn main() {
+    let iterations = env::var("BENCH_ITERATIONS")
+        .ok()
+        .and_then(|count| count.parse::<usize>().ok())
+        .unwrap_or(ITERATIONS);
+
+    for day in &DAYS {
+        let Ok(file) = InputSource::resolve(day.number, None).read() else {
+            println!("Day {:02}: skipped, no input\n", day.number);
+            continue;
+        };
+
+        println!("Day {:02}: {}", day.number, day.title);
+        match day.time(&file, iterations) {
+            Ok(timings) => println!("{}\n", timings),
+            Err(err) => println!("error: {}\n", err),
+        }
+    }
+}
@@ -0,0 +1,178 @@
+//! Recording simulations one step at a time.
+//!
+//! Simulations that can be animated take a [Recorder], and hand it a
+//! frame for each step they take. The recorder decides what happens
+//! to the frames: [Discard] ignores them, [Terminal] redraws them on
+//! a cleared screen, [FrameDump] writes them all out as plain text,
+//! and a [Vec] of [String]s just keeps them.
+//!
+//! Recording never stops a simulation. Recorders that can fail hold on
+//! to the first error instead, and report it when they're finished.
+
+use std::fmt::Display;
+use std::io::{self, Write};
+use std::thread;
+use std::time::Duration;
+
+/// Something that takes in the frames of an animation.
+pub trait Recorder {
+    /// Record the next frame of the animation.
+    fn frame(&mut self, frame: &dyn Display);
+}
+
+/// Throws every frame away, for running a simulation without
+/// animating it.
+pub struct Discard;
+
+impl Recorder for Discard {
+    fn frame(&mut self, _: &dyn Display) {}
+}
+
+impl Recorder for Vec<String> {
+    fn frame(&mut self, frame: &dyn Display) {
+        self.push(frame.to_string());
+    }
+}
+
+/// Plays an animation on a terminal, clearing the screen for each
+/// frame and waiting a while before moving on.
+///
+/// Clearing the whole screen means frames too wide for the terminal
+/// still get cleared away properly, however they wrap.
+pub struct Terminal<W> {
+    out: W,
+    delay: Duration,
+    error: Option<io::Error>,
+}
+
+impl<W: Write> Terminal<W> {
+    /// Play frames on `out`, showing each one for `delay`.
+    pub fn new(out: W, delay: Duration) -> Terminal<W> {
+        Terminal {
+            out,
+            delay,
+            error: None,
+        }
+    }
+
+    /// Stop playing, reporting the first error writing any frame.
+    pub fn finish(mut self) -> io::Result<()> {
+        match self.error {
+            Some(err) => Err(err),
+            None => self.out.flush(),
+        }
+    }
+
+    fn draw(&mut self, frame: &dyn Display) -> io::Result<()> {
+        // Clear the screen, and start again from the top left.
+        write!(self.out, "\x1b[2J\x1b[H")?;
+        writeln!(self.out, "{}", frame)?;
+        self.out.flush()?;
+
+        thread::sleep(self.delay);
+        Ok(())
+    }
+}
+
+impl<W: Write> Recorder for Terminal<W> {
+    fn frame(&mut self, frame: &dyn Display) {
+        if self.error.is_none() {
+            if let Err(err) = self.draw(frame) {
+                self.error = Some(err);
+            }
+        }
+    }
+}
+
+/// Writes every frame of an animation one after the other, each
+/// under a numbered heading, for reading through afterwards.
+pub struct FrameDump<W> {
+    out: W,
+    count: usize,
+    error: Option<io::Error>,
+}
+
+impl<W: Write> FrameDump<W> {
+    /// Write frames to `out`.
+    pub fn new(out: W) -> FrameDump<W> {
+        FrameDump {
+            out,
+            count: 0,
+            error: None,
+        }
+    }
+
+    /// Stop writing, reporting the first error writing any frame.
+    ///
+    /// On success, this gives back how many frames were written.
+    pub fn finish(mut self) -> io::Result<usize> {
+        match self.error {
+            Some(err) => Err(err),
+            None => self.out.flush().map(|_| self.count),
+        }
+    }
+}
+
+impl<W: Write> Recorder for FrameDump<W> {
+    fn frame(&mut self, frame: &dyn Display) {
+        if self.error.is_some() {
+            return;
+        }
+
+        let separator = if self.count > 0 { "\n" } else { "" };
+        self.count += 1;
+        let written = write!(self.out, "{}Frame {}:\n{}\n", separator, self.count, frame);
+        if let Err(err) = written {
+            self.error = Some(err);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_terminal() {
+        let mut terminal = Terminal::new(Vec::new(), Duration::ZERO);
+        terminal.frame(&"ab\ncd");
+        terminal.frame(&"ef\ngh");
+
+        assert_eq!(terminal.out, b"\x1b[2J\x1b[Hab\ncd\n\x1b[2J\x1b[Hef\ngh\n");
+        assert!(terminal.finish().is_ok());
+    }
+
+    #[test]
+    fn test_frame_dump() {
+        let mut dump = FrameDump::new(Vec::new());
+        dump.frame(&"ab\ncd");
+        dump.frame(&1234);
+
+        assert_eq!(dump.out, b"Frame 1:\nab\ncd\n\nFrame 2:\n1234\n");
+        assert_eq!(dump.finish().unwrap(), 2);
+    }
+
+    #[test]
+    fn test_errors() {
+        struct Broken;
+
+        impl Write for Broken {
+            fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+                Err(io::Error::other("broken"))
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let mut dump = FrameDump::new(Broken);
+        dump.frame(&"one");
+        dump.frame(&"two");
+        assert_eq!(dump.finish().unwrap_err().to_string(), "broken");
+
+        let mut terminal = Terminal::new(Broken, Duration::ZERO);
+        terminal.frame(&"one");
+        assert!(terminal.finish().is_err());
+    }
+}
@@ -0,0 +1,207 @@
+//! Stored answers to each day's puzzle.
+//!
+//! Once an answer has been accepted, it can be written down in an
+//! answers file so that later changes can be checked against it.
+//! The file is a small subset of TOML, with one table per day:
+//!
+//! ```toml
+//! [day01]
+//! part_one = 11
+//! part_two = 31
+//!
+//! [day13]
+//! part_one = "480"
+//! ```
+//!
+//! Values can be bare or quoted, and `#` outside of quotes
+//! starts a comment.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::{AdventError, Part};
+
+/// The name of the answers file inside the input directory.
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+/// The accepted answers for each part of each day.
+pub struct Answers(HashMap<(u8, Part), String>);
+
+impl Answers {
+    /// Parse the contents of an answers file.
+    pub fn parse(text: &str) -> Result<Answers, AdventError> {
+        let mut answers = HashMap::new();
+        let mut day = None;
+
+        for line in text.lines() {
+            let line = strip_comment(line).trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            if let Some(table) = line.strip_prefix('[').and_then(|t| t.strip_suffix(']')) {
+                let number = table
+                    .trim()
+                    .strip_prefix("day")
+                    .and_then(|number| number.parse::<u8>().ok())
+                    .ok_or_else(|| AdventError::syntax(text, table, "a day like `day01`"))?;
+                day = Some(number);
+                continue;
+            }
+
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| AdventError::syntax(text, line, "`key = value`"))?;
+            let part = match key.trim() {
+                "part_one" => Part::One,
+                "part_two" => Part::Two,
+                key => return Err(AdventError::syntax(text, key, "`part_one` or `part_two`")),
+            };
+            let Some(day) = day else {
+                return Err(AdventError::syntax(
+                    text,
+                    line,
+                    "a `[dayXX]` table before any answers",
+                ));
+            };
+
+            let value = value.trim();
+            let value = match value.strip_prefix('"') {
+                Some(quoted) => quoted.strip_suffix('"').ok_or_else(|| {
+                    AdventError::syntax(text, value, "a value with a closing quote")
+                })?,
+                None => value,
+            };
+            answers.insert((day, part), value.to_string());
+        }
+
+        Ok(Answers(answers))
+    }
+
+    /// Get the accepted answer to `part` of `day`, if there is one.
+    pub fn get(&self, day: u8, part: Part) -> Option<&str> {
+        self.0.get(&(day, part)).map(String::as_str)
+    }
+
+    /// Compare the answer `found` for `part` of `day` against the
+    /// accepted one.
+    ///
+    /// `found` should be [Option::None] if the part hasn't been solved.
+    pub fn check(&self, day: u8, part: Part, found: Option<&str>) -> Verdict {
+        match (self.get(day, part), found) {
+            (_, None) => Verdict::Unsolved,
+            (None, Some(_)) => Verdict::Missing,
+            (Some(expected), Some(found)) if expected == found => Verdict::Pass,
+            (Some(expected), Some(found)) => Verdict::Fail {
+                expected: expected.to_string(),
+                found: found.to_string(),
+            },
+        }
+    }
+}
+
+/// Cut off any comment at the end of `line`.
+///
+/// A `#` only starts a comment outside of quotes, so
+/// quoted answers can have one in them.
+fn strip_comment(line: &str) -> &str {
+    let mut quoted = false;
+    for (idx, ch) in line.char_indices() {
+        match ch {
+            '"' => quoted = !quoted,
+            '#' if !quoted => return &line[..idx],
+            _ => {}
+        }
+    }
+
+    line
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+/// The result of checking an answer against the accepted one.
+pub enum Verdict {
+    /// The answer matches.
+    Pass,
+    /// The answer doesn't match. Something broke!
+    Fail { expected: String, found: String },
+    /// There is no accepted answer to compare against.
+    Missing,
+    /// The part hasn't been solved, so there's no answer to check.
+    Unsolved,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "pass"),
+            Verdict::Fail { expected, found } => {
+                write!(f, "FAIL (expected {}, found {})", expected, found)
+            }
+            Verdict::Missing => write!(f, "missing"),
+            Verdict::Unsolved => write!(f, "unsolved"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    static ANSWERS: &str = "# Sample answers
+[day01]
+part_one = 11
+part_two = \"31\" # quoted
+
+[day13]
+part_one = 480
+";
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+
+        assert_eq!(answers.get(1, Part::One), Some("11"));
+        assert_eq!(answers.get(1, Part::Two), Some("31"));
+        assert_eq!(answers.get(13, Part::One), Some("480"));
+        assert_eq!(answers.get(13, Part::Two), None);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(Answers::parse("part_one = 11").is_err());
+        assert!(Answers::parse("[dayone]").is_err());
+        assert!(Answers::parse("[day01]\npart_three = 1").is_err());
+        assert!(Answers::parse("[day01]\npart_one 11").is_err());
+        assert!(Answers::parse("[day01]\npart_one = \"11").is_err());
+
+        let err = Answers::parse("[day01]\npart_one = 11\npart_three = 1").unwrap_err();
+        let location = err.location().unwrap();
+        assert_eq!((location.line, location.column), (3, 1));
+        assert_eq!(location.snippet, "part_three");
+    }
+
+    #[test]
+    fn test_quoted_comments() {
+        let answers =
+            Answers::parse("[day03]\npart_one = \"#1 # not a comment\" # a comment").unwrap();
+
+        assert_eq!(answers.get(3, Part::One), Some("#1 # not a comment"));
+    }
+
+    #[test]
+    fn test_check() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+
+        assert_eq!(answers.check(1, Part::One, Some("11")), Verdict::Pass);
+        assert_eq!(
+            answers.check(1, Part::Two, Some("30")),
+            Verdict::Fail {
+                expected: "31".to_string(),
+                found: "30".to_string()
+            }
+        );
+        assert_eq!(answers.check(13, Part::Two, Some("1")), Verdict::Missing);
+        assert_eq!(answers.check(13, Part::Two, None), Verdict::Unsolved);
+    }
+}
@@ -0,0 +1,271 @@
+//! Labelling the connected regions of a grid.
+//!
+//! Two cells are in the same component if they hold equal values and
+//! can be reached from one another by cardinal steps through cells
+//! holding that same value.
+
+use simple_grid::{Grid, GridIndex};
+
+use crate::direction::{Compass8, Delta, Direction};
+use crate::grid::GridExt;
+use crate::search::{self, Flow};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// The smallest rectangle containing a set of cells, inclusive.
+pub struct Bounds {
+    pub top_left: GridIndex,
+    pub bottom_right: GridIndex,
+}
+
+impl Bounds {
+    fn of(cells: &[GridIndex]) -> Bounds {
+        let column = cells.iter().map(|idx| idx.column());
+        let row = cells.iter().map(|idx| idx.row());
+
+        Bounds {
+            top_left: GridIndex::new(
+                column.clone().min().unwrap_or(0),
+                row.clone().min().unwrap_or(0),
+            ),
+            bottom_right: GridIndex::new(column.max().unwrap_or(0), row.max().unwrap_or(0)),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.bottom_right.column() - self.top_left.column() + 1
+    }
+
+    pub fn height(&self) -> usize {
+        self.bottom_right.row() - self.top_left.row() + 1
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+/// A single connected region of a grid.
+pub struct Component<T> {
+    symbol: T,
+    cells: Vec<GridIndex>,
+    bounds: Bounds,
+    perimeter: usize,
+    sides: usize,
+}
+
+impl<T> Component<T> {
+    /// The value every cell in this component holds.
+    pub fn symbol(&self) -> &T {
+        &self.symbol
+    }
+
+    /// The cells in this component, in the order they were found.
+    pub fn cells(&self) -> &[GridIndex] {
+        &self.cells
+    }
+
+    pub fn bounds(&self) -> Bounds {
+        self.bounds
+    }
+
+    /// The number of cells in this component.
+    pub fn area(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// The number of cell edges on the boundary of this component.
+    pub fn perimeter(&self) -> usize {
+        self.perimeter
+    }
+
+    /// The number of straight sides the boundary of this component has.
+    pub fn sides(&self) -> usize {
+        self.sides
+    }
+}
+
+#[derive(Clone, Debug)]
+/// Every component of a grid, along with which one each cell is in.
+pub struct Components<T> {
+    labels: Grid<usize>,
+    components: Vec<Component<T>>,
+}
+
+impl<T> Components<T> {
+    /// A grid the same shape as the original, holding the
+    /// label of the component each cell is in.
+    pub fn labels(&self) -> &Grid<usize> {
+        &self.labels
+    }
+
+    /// The component with a given label.
+    pub fn get(&self, label: usize) -> Option<&Component<T>> {
+        self.components.get(label)
+    }
+
+    /// The component a cell is in.
+    pub fn at(&self, idx: GridIndex) -> Option<&Component<T>> {
+        self.get(*self.labels.get(idx)?)
+    }
+
+    /// Every component, in order of their labels.
+    pub fn iter(&self) -> impl Iterator<Item = &Component<T>> {
+        self.components.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.components.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.components.is_empty()
+    }
+}
+
+impl<T> IntoIterator for Components<T> {
+    type Item = Component<T>;
+    type IntoIter = std::vec::IntoIter<Component<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.components.into_iter()
+    }
+}
+
+/// Split a grid up into its connected components.
+///
+/// Labels count up from zero in the order each component's first
+/// cell appears, reading left to right and top to bottom.
+pub fn label<T: Clone + PartialEq>(grid: &Grid<T>) -> Components<T> {
+    let mut labels = Grid::new(grid.width(), grid.height(), vec![usize::MAX; grid.area()]);
+    let mut components = Vec::new();
+
+    for idx in grid.indices() {
+        if labels[idx] != usize::MAX {
+            continue;
+        }
+
+        let symbol = &grid[idx];
+        let explored = search::bfs(
+            idx,
+            |&idx| search::cardinal(grid, idx).filter(|&next| grid[next] == *symbol),
+            |_| Flow::Continue,
+        );
+
+        let cells = explored.visited().to_vec();
+        for &cell in &cells {
+            labels[cell] = components.len();
+        }
+
+        components.push(Component {
+            symbol: symbol.clone(),
+            bounds: Bounds::of(&cells),
+            perimeter: perimeter_of(grid, &cells),
+            sides: sides_of(grid, &cells),
+            cells,
+        });
+    }
+
+    Components { labels, components }
+}
+
+/// Whether the cell a step away from `idx` holds the same value.
+fn same<T: PartialEq>(grid: &Grid<T>, idx: GridIndex, dir: impl Delta) -> bool {
+    grid.step(idx, dir)
+        .is_some_and(|next| grid[next] == grid[idx])
+}
+
+/// The perimeter of a component, given all of its cells.
+///
+/// Cells only count as inside when they hold the same value, so this
+/// is only right for a whole component, not any set of cells.
+fn perimeter_of<T: PartialEq>(grid: &Grid<T>, cells: &[GridIndex]) -> usize {
+    cells
+        .iter()
+        .map(|&idx| {
+            Direction::ALL
+                .into_iter()
+                .filter(|&dir| !same(grid, idx, dir))
+                .count()
+        })
+        .sum()
+}
+
+/// The number of straight sides of a component, given all of its cells.
+///
+/// As with [perimeter_of], this only works for a whole component.
+///
+/// A closed shape has as many sides as it has corners, and corners are
+/// easier to spot: looking at each cell's four corners, either both of
+/// the neighbors beside a corner are outside the region (it sticks out),
+/// or both are inside but the one diagonally across is not (it dents in).
+fn sides_of<T: PartialEq>(grid: &Grid<T>, cells: &[GridIndex]) -> usize {
+    cells
+        .iter()
+        .map(|&idx| {
+            Direction::ALL
+                .into_iter()
+                .filter(|&dir| {
+                    let ahead = same(grid, idx, dir);
+                    let beside = same(grid, idx, dir.turn_right());
+                    let across = same(grid, idx, Compass8::from(dir).turn_right());
+
+                    (!ahead && !beside) || (ahead && beside && !across)
+                })
+                .count()
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::parse;
+
+    #[test]
+    fn test_label() {
+        let grid = parse::char_grid("AAAA\nBBCD\nBBCC\nEEEC").unwrap();
+        let components = label(&grid);
+
+        assert_eq!(components.len(), 5);
+        assert_eq!(components.labels()[GridIndex::new(3, 3)], 2);
+
+        let stats = components
+            .iter()
+            .map(|c| (*c.symbol(), c.area(), c.perimeter(), c.sides()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            stats,
+            vec![
+                ('A', 4, 10, 4),
+                ('B', 4, 8, 4),
+                ('C', 4, 10, 8),
+                ('D', 1, 4, 4),
+                ('E', 3, 8, 4),
+            ]
+        );
+
+        let c = components.at(GridIndex::new(2, 1)).unwrap();
+        assert_eq!(c.bounds().top_left, GridIndex::new(2, 1));
+        assert_eq!((c.bounds().width(), c.bounds().height()), (2, 3));
+    }
+
+    #[test]
+    fn test_separate_regions() {
+        // The two outer X's touch only diagonally, so they are apart.
+        let grid = parse::char_grid("XO\nOX").unwrap();
+        let components = label(&grid);
+
+        assert_eq!(components.len(), 4);
+        assert!(components.iter().all(|c| c.area() == 1 && c.sides() == 4));
+    }
+
+    #[test]
+    fn test_holes() {
+        // The outside and the hole both count towards the sides.
+        let grid = parse::char_grid("OOOOO\nOXOXO\nOOOOO\nOXOXO\nOOOOO").unwrap();
+        let components = label(&grid);
+
+        let outer = components.get(0).unwrap();
+        assert_eq!(outer.area(), 21);
+        assert_eq!(outer.sides(), 20);
+        assert_eq!(outer.perimeter(), 36);
+    }
+}
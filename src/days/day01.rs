@@ -6,47 +6,25 @@
 //!
 //! Are you a good enough elf to save the Historian?
 
-use advent2024::AdventError;
-use std::fs::read_to_string;
+use crate::{parse, AdventError, Solution};
 
 #[derive(Debug, PartialEq, Eq)]
-struct Lists(Vec<(u32, u32)>);
+pub struct Lists(Vec<(u32, u32)>);
 
 /// Input consists of a list of numbers. Each row contains two
 /// numbers separated by whitespace.
-fn parse_input(text: &str) -> Result<Lists, AdventError> {
-    let lines: Vec<(u32, u32)> = text
-        .lines()
-        // Split each line into two numbers.
-        .map(|line| {
-            let mut numbers = line.split_ascii_whitespace();
-            let one = numbers.next();
-            let two = numbers.next();
-
-            (one, two)
-        })
-        // Ensure that both pairs exist.
-        .filter_map(|pair| match pair {
-            (Some(one), Some(two)) => Some((one.to_string(), two.to_string())),
-            _ => None,
-        })
-        // Double check that both pairs are valid numbers.
-        .map(|(one, two)| {
-            let one = one
-                .parse::<u32>()
-                .map_err(|err| AdventError::Parse(format!("ParseIntError: {}", err)));
-            let two = two
-                .parse::<u32>()
-                .map_err(|err| AdventError::Parse(format!("ParseIntError: {}", err)));
-            match (one, two) {
-                (Ok(one), Ok(two)) => Ok((one, two)),
-                (Err(one), _) => Err(one),
-                (Ok(_), Err(two)) => Err(two),
-            }
-        })
+pub fn parse_input(text: &str) -> Result<Lists, AdventError> {
+    let lines = parse::lines(text, |line| {
+        // Split each line into two numbers...
+        let numbers = parse::numbers::<u32>(text, line)?;
+
+        // ...and make sure there are exactly two of them.
+        match numbers[..] {
+            [one, two] => Ok((one, two)),
+            _ => Err(AdventError::syntax(text, line, "two numbers")),
+        }
+    })?;
 
-        // Gather everything up and fail if numerical conversion failed.
-        .collect::<Result<Vec<_>, AdventError>>()?;
     Ok(Lists(lines))
 }
 
@@ -57,7 +35,7 @@ fn parse_input(text: &str) -> Result<Lists, AdventError> {
 /// the smallest value in both lists plus the distance between
 /// the second smallest value in both lists, all the way up to
 /// the distance between the largest values in both lists.
-fn part_one(data: &Lists) -> u32 {
+pub fn part_one(data: &Lists) -> u32 {
     let (one, two): (Vec<_>, Vec<_>) = data.0.clone().into_iter().unzip();
     let mut one: Vec<_> = one.clone();
     let mut two: Vec<_> = two.clone();
@@ -78,7 +56,7 @@ fn part_one(data: &Lists) -> u32 {
 ///
 /// The similarity score is measured as the number of times a
 /// value in list 1 appears in list 2.
-fn part_two(data: &Lists) -> usize {
+pub fn part_two(data: &Lists) -> usize {
     let (one, two): (Vec<_>, Vec<_>) = data.0.clone().into_iter().unzip();
 
     one.into_iter()
@@ -93,18 +71,35 @@ fn part_two(data: &Lists) -> usize {
         .sum()
 }
 
-fn main() -> Result<(), AdventError> {
-    let file = read_to_string("src/input/day01.txt")?;
-    let data = parse_input(&file)?;
+/// The solution to day 01.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Historian Hysteria";
+
+    type Input = Lists;
+    type PartOne = u32;
+    type PartTwo = usize;
+
+    fn parse_input(text: &str) -> Result<Self::Input, AdventError> {
+        parse_input(text)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne, AdventError> {
+        Ok(part_one(input))
+    }
 
-    println!("The total distance is {}", part_one(&data));
-    println!("The similarity score is {}", part_two(&data));
-    Ok(())
+    fn part_two(input: &Self::Input) -> Result<Option<Self::PartTwo>, AdventError> {
+        Ok(Some(part_two(input)))
+    }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use std::fs::read_to_string;
 
     #[cfg(test)]
     fn load_input() -> String {
@@ -122,6 +117,15 @@ mod test {
         assert_eq!(data.0[0], (3, 4));
     }
 
+    #[test]
+    fn test_parse_error() {
+        let err = parse_input("3   4\n4   x3\n").unwrap_err();
+        let location = err.location().unwrap();
+
+        assert_eq!((location.line, location.column), (2, 5));
+        assert_eq!(location.snippet, "x3");
+    }
+
     #[test]
     fn test_part_one() {
         let file = parse_input(&load_input());
@@ -6,11 +6,10 @@
 
 #![warn(clippy::all)]
 
-use advent2024::AdventError;
-use std::fs::read_to_string;
+use crate::{parse, AdventError, Solution};
 
 #[derive(Clone, PartialEq, Eq, Debug)]
-struct Report(Vec<u32>);
+pub struct Report(Vec<u32>);
 
 // This impl block basically exists to make sure
 // I can test both of the conditions for part one's puzzle.
@@ -38,20 +37,8 @@ impl Report {
 
 /// Puzzle input consists of a series of reports. A report in this
 /// context is defined as a series of numbers separated by spaces.
-fn parse_input(text: &str) -> Result<Vec<Report>, AdventError> {
-    text.lines()
-        .map(|line| {
-            let report = line
-                .split_whitespace()
-                .map(|num| {
-                    num.parse::<u32>()
-                        .map_err(|err| AdventError::Parse(format!("ParseIntError: {}", err)))
-                })
-                .collect::<Result<Vec<u32>, AdventError>>();
-
-            report.map(Report)
-        })
-        .collect::<Result<Vec<_>, AdventError>>()
+pub fn parse_input(text: &str) -> Result<Vec<Report>, AdventError> {
+    parse::lines(text, |line| parse::numbers(text, line).map(Report))
 }
 
 /// Find the number of safe reports.
@@ -61,7 +48,7 @@ fn parse_input(text: &str) -> Result<Vec<Report>, AdventError> {
 /// 1. All of the numbers are sorted in either ascending
 ///    descending order.
 /// 2. No number changes by less than one or more than three.
-fn part_one(data: &[Report]) -> usize {
+pub fn part_one(data: &[Report]) -> usize {
     data.iter()
         // Check condition 1. The `is_sorted_by` function is
         // used so that we can check a reverse ordering.
@@ -76,7 +63,7 @@ fn part_one(data: &[Report]) -> usize {
 /// The definition of safe remains the same, but now there's a new
 /// wrinkle: we can remove one item from a report and still have it
 /// qualify as safe.
-fn part_two(data: &[Report]) -> usize {
+pub fn part_two(data: &[Report]) -> usize {
     let (passed, failed): (Vec<_>, Vec<_>) = data
         .iter()
         .partition(|report| report.is_sorted() && report.has_safe_delta());
@@ -97,22 +84,35 @@ fn part_two(data: &[Report]) -> usize {
     passed.len() + failed
 }
 
-fn main() -> Result<(), AdventError> {
-    let file = read_to_string("src/input/day02.txt")?;
-    let data = parse_input(&file)?;
+/// The solution to day 02.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "Red-Nosed Reports";
 
-    println!("The number of safe reports is {}", part_one(&data));
-    println!(
-        "The number of safe reports after dampening is {}",
-        part_two(&data)
-    );
+    type Input = Vec<Report>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse_input(text: &str) -> Result<Self::Input, AdventError> {
+        parse_input(text)
+    }
 
-    Ok(())
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne, AdventError> {
+        Ok(part_one(input))
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Option<Self::PartTwo>, AdventError> {
+        Ok(Some(part_two(input)))
+    }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use std::fs::read_to_string;
 
     use std::sync::LazyLock;
 
@@ -149,7 +149,7 @@ mod test {
 
     #[test]
     fn test_part_one() {
-        assert_eq!(part_one(&*INPUT), 2);
+        assert_eq!(part_one(&INPUT), 2);
     }
 
     #[test]
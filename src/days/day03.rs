@@ -6,9 +6,8 @@
 
 #![warn(clippy::all)]
 
-use advent2024::AdventError;
+use crate::{AdventError, Solution};
 
-use std::fs::read_to_string;
 use std::sync::LazyLock;
 
 use regex::Regex;
@@ -19,7 +18,7 @@ static INSTRUCTION_RE: LazyLock<Regex> = LazyLock::new(|| {
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 /// The instructions the machine can perform.
-enum Instruction {
+pub enum Instruction {
     Multiply(u32, u32),
     Enable,
     Disable,
@@ -47,7 +46,7 @@ impl Instruction {
 /// Syntax errors are to be expected, as this data is
 /// corrupted. As such we shouldn't fail when we see
 /// something we don't expect.
-fn parse_input(file: &str) -> Vec<Instruction> {
+pub fn parse_input(file: &str) -> Vec<Instruction> {
     INSTRUCTION_RE
         .find_iter(file)
         .map(|m| m.as_str())
@@ -81,7 +80,7 @@ fn parse_input(file: &str) -> Vec<Instruction> {
 }
 
 /// Find the sum of the results of all of the multiply instructions.
-fn part_one(data: &[Instruction]) -> u32 {
+pub fn part_one(data: &[Instruction]) -> u32 {
     data.iter().filter_map(|mul| mul.compute()).sum()
 }
 
@@ -91,7 +90,7 @@ fn part_one(data: &[Instruction]) -> u32 {
 /// because we have to keep track of the state of the
 /// machine. `do()` and `don't()` respectively enable and
 /// disable the machine's ability to do multiplications.
-fn part_two(data: &[Instruction]) -> u32 {
+pub fn part_two(data: &[Instruction]) -> u32 {
     let (_, out) = data
         .iter()
         .fold((true, 0), |(power, acc), &code| match code {
@@ -106,24 +105,35 @@ fn part_two(data: &[Instruction]) -> u32 {
     out
 }
 
-fn main() -> Result<(), AdventError> {
-    let file = read_to_string("src/input/day03.txt")?;
-    let data = parse_input(&file);
-
-    println!(
-        "The sum of the multiply instructions is {}",
-        part_one(&data)
-    );
-    print!(
-        "The sum of the enabled multiply instructions is {}",
-        part_two(&data)
-    );
-    Ok(())
+/// The solution to day 03.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Mull It Over";
+
+    type Input = Vec<Instruction>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse_input(text: &str) -> Result<Self::Input, AdventError> {
+        Ok(parse_input(text))
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne, AdventError> {
+        Ok(part_one(input))
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Option<Self::PartTwo>, AdventError> {
+        Ok(Some(part_two(input)))
+    }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use std::fs::read_to_string;
     use std::sync::LazyLock;
 
     static INPUT: LazyLock<Vec<Instruction>> = LazyLock::new(|| {
@@ -136,7 +146,6 @@ mod test {
     fn test_parse_input() {
         let muls = &*INPUT;
 
-        println!("{:?}", muls);
         assert_eq!(muls.len(), 4);
         assert_eq!(muls[0], Instruction::Multiply(2, 4));
     }
@@ -145,7 +154,7 @@ mod test {
     fn test_part_one() {
         let muls = &*INPUT;
 
-        assert_eq!(part_one(&muls), 161);
+        assert_eq!(part_one(muls), 161);
     }
 
     #[test]
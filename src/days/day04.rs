@@ -0,0 +1,187 @@
+//! Day 04: Ceres Search
+//!
+//! ...we need to solve a word search. Gosh dang it.
+
+use crate::direction::Compass8;
+use crate::grid::GridExt;
+use crate::{parse, AdventError, Solution};
+use simple_grid::{Grid, GridIndex};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// The possible characters in the word search.
+pub enum Letter {
+    X,
+    M,
+    A,
+    S,
+}
+
+/// Input consists of a grid of letters.
+pub fn parse_input(file: &str) -> Result<Grid<Letter>, AdventError> {
+    parse::grid(file, |ch, _| match ch {
+        'X' => Ok(Letter::X),
+        'M' => Ok(Letter::M),
+        'A' => Ok(Letter::A),
+        'S' => Ok(Letter::S),
+        _ => Err("one of `X`, `M`, `A` or `S`"),
+    })
+}
+
+/// Find the number of times "XMAS" appears in the word search.
+///
+/// You're _joking_, right? Right?
+pub fn part_one(data: &Grid<Letter>) -> usize {
+    data.indices()
+        .filter(|&idx| data[idx] == Letter::X)
+        .map(|idx| {
+            // Walk each direction in turn, looking for the rest of the word.
+            Compass8::ALL
+                .into_iter()
+                .filter(|&dir| {
+                    let mut idx = Some(idx);
+                    [Letter::M, Letter::A, Letter::S].into_iter().all(|letter| {
+                        idx = idx.and_then(|idx| data.step(idx, dir));
+                        idx.is_some_and(|idx| data[idx] == letter)
+                    })
+                })
+                .count()
+        })
+        .sum()
+}
+
+/// Find the number of crosses in the grid.
+///
+/// A cross resembles the following:
+/// ```notrust
+/// M.S
+/// .A.
+/// M.S
+/// ```
+pub fn part_two(grid: &Grid<Letter>) -> usize {
+    grid.indices()
+        .filter(|&idx| grid[idx] == Letter::A)
+        .filter(|&idx| find_crosses(grid, idx))
+        .count()
+}
+
+/// Given an index, find the number of crosses it is a part of.
+///
+/// Note that crosses only count if the lines are _diagonal_,
+/// not horizontal or vertical.
+fn find_crosses(grid: &Grid<Letter>, idx: GridIndex) -> bool {
+    // This is a pathological check. It _should_ never get
+    // to this point, but just in case it does...
+    assert!(grid[idx] == Letter::A);
+
+    use Letter::*;
+
+    // Get the letters at either end of each diagonal.
+    let letter = |dir| grid.step(idx, dir).map(|idx| grid[idx]);
+    let lines = [
+        [letter(Compass8::NorthEast), letter(Compass8::SouthWest)],
+        [letter(Compass8::SouthEast), letter(Compass8::NorthWest)],
+    ];
+
+    matches!(lines[0], [Some(M), Some(S)] | [Some(S), Some(M)])
+        && matches!(lines[1], [Some(M), Some(S)] | [Some(S), Some(M)])
+}
+
+/// The solution to day 04.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "Ceres Search";
+
+    type Input = Grid<Letter>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse_input(text: &str) -> Result<Self::Input, AdventError> {
+        parse_input(text)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne, AdventError> {
+        Ok(part_one(input))
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Option<Self::PartTwo>, AdventError> {
+        Ok(Some(part_two(input)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::assert_grid_eq;
+    use std::fs::read_to_string;
+
+    use std::sync::LazyLock;
+
+    static INPUT: LazyLock<Grid<Letter>> = LazyLock::new(|| {
+        let file = read_to_string("src/input/day04-test.txt").unwrap();
+
+        parse_input(&file).unwrap()
+    });
+
+    #[test]
+    fn test_parse_input() {
+        use Letter::*;
+        let grid = &*INPUT;
+        let row = grid.row_iter(0).cloned().collect::<Vec<_>>();
+
+        assert_eq!(row, vec![M, M, M, S, X, X, M, A, S, M]);
+
+        let grid = parse_input("XMAS\nSAMX").unwrap();
+        let letter = |letter: &Letter| match letter {
+            X => 'X',
+            M => 'M',
+            A => 'A',
+            S => 'S',
+        };
+
+        assert_grid_eq!(grid, Grid::new(4, 2, vec![X, M, A, S, S, A, M, X]), letter);
+    }
+
+    #[test]
+    fn test_part_one() {
+        let grid = &*INPUT;
+
+        assert_eq!(part_one(grid), 18);
+    }
+
+    #[test]
+    fn test_find_crosses() {
+        use Letter::*;
+
+        // Cardinal neighbors _do not_ count as crosses.
+        let plus = vec![
+            X, M, X, // row 0
+            M, A, S, // row 1
+            X, S, X, // row 2
+        ];
+        let grid = Grid::new(3, 3, plus);
+        let center = GridIndex::new(1, 1);
+        assert!(grid[center] == A);
+
+        assert!(!find_crosses(&grid, center));
+
+        // It _has_ to be diagonal.
+        let cross = vec![
+            S, X, M, // row 0
+            X, A, X, // row 1
+            S, X, M, // row 2
+        ];
+        let grid = Grid::new(3, 3, cross);
+
+        assert!(find_crosses(&grid, center));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let grid = &*INPUT;
+
+        assert_eq!(part_two(grid), 9);
+    }
+}
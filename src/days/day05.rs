@@ -5,12 +5,11 @@
 //! of pages, lots of rules, and not enough
 //! time to figure it out manually!
 
-use advent2024::AdventError;
+use crate::{parse, AdventError, Solution};
 use std::cmp::Ordering;
-use std::fs::read_to_string;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-struct PageOrdering(Vec<(u32, u32)>);
+pub struct PageOrdering(Vec<(u32, u32)>);
 
 impl PageOrdering {
     /// Check to see whether or not `one` and `two`
@@ -25,7 +24,7 @@ impl PageOrdering {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-struct Update(Vec<u32>);
+pub struct Update(Vec<u32>);
 
 impl Update {
     /// Get the median page of this update.
@@ -42,55 +41,33 @@ impl Update {
 ///
 /// A series of pages consists of a series of
 /// comma separated numbers.
-fn parse_input(file: &str) -> Result<(PageOrdering, Vec<Update>), AdventError> {
-    let (rules, update) = file.split_once("\n\n").ok_or(AdventError::Parse(
-        "Expected two-part data format".to_string(),
-    ))?;
-
-    let rules = rules
-        .lines()
-        .map(|line| {
-            let (one, two) = line.split_once("|").ok_or(AdventError::Parse(format!(
-                "Invalid page ordering rule {}",
-                line
-            )))?;
-
-            let Ok(one) = one.parse::<u32>() else {
-                return Err(AdventError::Parse(format!("Invalid page number {}", one)));
-            };
-            let Ok(two) = two.parse::<u32>() else {
-                return Err(AdventError::Parse(format!("Invalid page number {}", two)));
-            };
-
-            Ok((one, two))
-        })
-        .collect::<Result<Vec<(u32, u32)>, AdventError>>()?;
-
-    let ordering = PageOrdering(rules);
-
-    let updates = update
-        .lines()
-        .map(|line| {
-            let updates = line
-                .split(",")
-                .map(|num| {
-                    num.parse::<u32>()
-                        .map_err(|_| AdventError::Parse(format!("Invalid page number {}", num)))
-                })
-                .collect::<Result<Vec<u32>, AdventError>>()?;
-
-            Ok(Update(updates))
-        })
-        .collect::<Result<Vec<_>, AdventError>>()?;
+pub fn parse_input(file: &str) -> Result<(PageOrdering, Vec<Update>), AdventError> {
+    let mut sections = parse::sections(file);
+    let rules = sections.next().unwrap_or(file);
+    let updates = sections.next().ok_or_else(|| {
+        AdventError::syntax(file, rules, "the rules, a blank line, and then the updates")
+    })?;
+    if let Some(extra) = sections.next() {
+        return Err(AdventError::syntax(
+            file,
+            extra,
+            "nothing after the updates",
+        ));
+    }
+
+    let rules = parse::lines(rules, |line| parse::pair(file, line, "|"))?;
+    let updates = parse::lines(updates, |line| {
+        parse::separated(file, line, ",").map(Update)
+    })?;
 
-    Ok((ordering, updates))
+    Ok((PageOrdering(rules), updates))
 }
 
 /// Find the sum of the median of successful updates.
 ///
 /// An update is successful if its pages are ordered
 /// according to the [PageOrdering] given by `rules`.
-fn part_one(rules: &PageOrdering, updates: &[Update]) -> u32 {
+pub fn part_one(rules: &PageOrdering, updates: &[Update]) -> u32 {
     updates
         .iter()
         .filter(|up| up.0.is_sorted_by(|a, b| rules.compare(*a, *b)))
@@ -99,7 +76,7 @@ fn part_one(rules: &PageOrdering, updates: &[Update]) -> u32 {
 }
 
 /// Fix the unsuccessful updates and sum their medians.
-fn part_two(rules: &PageOrdering, updates: &[Update]) -> u32 {
+pub fn part_two(rules: &PageOrdering, updates: &[Update]) -> u32 {
     let failed = updates
         .iter()
         .filter(|up| !up.0.is_sorted_by(|&a, &b| rules.compare(a, b)));
@@ -116,21 +93,35 @@ fn part_two(rules: &PageOrdering, updates: &[Update]) -> u32 {
         .sum()
 }
 
-fn main() -> Result<(), AdventError> {
-    let file = read_to_string("src/input/day05.txt")?;
-    let (rules, updates) = parse_input(&file)?;
+/// The solution to day 05.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "Print Queue";
+
+    type Input = (PageOrdering, Vec<Update>);
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse_input(text: &str) -> Result<Self::Input, AdventError> {
+        parse_input(text)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne, AdventError> {
+        Ok(part_one(&input.0, &input.1))
+    }
 
-    println!(
-        "The medians of successful updates sum to {}",
-        part_one(&rules, &updates)
-    );
-    println!("The medians of fixed updates sum to {}", part_two(&rules, &updates));
-    Ok(())
+    fn part_two(input: &Self::Input) -> Result<Option<Self::PartTwo>, AdventError> {
+        Ok(Some(part_two(&input.0, &input.1)))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::fs::read_to_string;
 
     use std::sync::LazyLock;
 
@@ -146,6 +137,11 @@ mod tests {
 
         assert_eq!(rules.0[0], (47, 53));
         assert_eq!(updates[0].0, vec![75, 47, 61, 53, 29]);
+
+        let err = parse_input("1|2\n3|4\n").unwrap_err();
+        assert_eq!(err.location().map(|location| location.line), Some(1));
+        let err = parse_input("1|2\n\n1,2\n\n3|4\n").unwrap_err();
+        assert_eq!(err.location().map(|location| location.line), Some(5));
     }
 
     #[test]
@@ -161,4 +157,12 @@ mod tests {
 
         assert_eq!(part_two(rules, updates), 123);
     }
+
+    #[test]
+    fn test_solution() {
+        let input = &*INPUT;
+
+        assert_eq!(Day05::part_one(input).unwrap(), 143);
+        assert_eq!(Day05::part_two(input).unwrap(), Some(123));
+    }
 }
@@ -0,0 +1,590 @@
+//! Day 06: Guard Gallivant
+//!
+//! We done did a time travel. Oops. Now we
+//! need to be careful to avoid the locals
+//! so we don't cause time whoopsies!
+
+use std::collections::{HashMap, HashSet};
+
+use crate::animate::Recorder;
+use crate::direction::{Delta, Direction};
+use crate::grid::GridExt;
+use crate::{parse, AdventError, Solution};
+
+use simple_grid::{Grid, GridIndex};
+
+#[derive(Clone, Copy, Hash, PartialEq, Eq, Debug)]
+/// A guard from the good old year of 1518.
+pub struct Guard {
+    position: GridIndex,
+    direction: Direction,
+}
+
+impl Guard {
+    /// Where on the map the guard is.
+    pub fn position(&self) -> GridIndex {
+        self.position
+    }
+}
+
+impl Default for Guard {
+    fn default() -> Self {
+        Guard {
+            position: GridIndex::new(0, 0),
+            direction: Direction::North,
+        }
+    }
+}
+
+/// Input consists of a grid of characters representing the map.
+///
+/// The grid contains one of three characters.
+/// 1. `.` representing empty space.
+/// 2. `#` representing one of the many bits of junk making a mess of
+///    the space.
+/// 3. `^` representing the guard's initial position.
+///
+/// There has to be exactly one guard on the map.
+pub fn parse_input(file: &str) -> Result<(Guard, Grid<bool>), AdventError> {
+    let mut start = None;
+
+    let grid = parse::grid(file, |ch, idx| match ch {
+        '.' => Ok(false),
+        '#' => Ok(true),
+        '^' if start.is_some() => Err("one of `.` or `#`, since there is only one guard"),
+        '^' => {
+            start = Some(idx);
+            Ok(false)
+        }
+        _ => Err("one of `.`, `#` or `^`"),
+    })?;
+
+    let position =
+        start.ok_or_else(|| AdventError::Data("There is no guard `^` on the map".to_string()))?;
+    let guard = Guard {
+        position,
+        ..Default::default()
+    };
+
+    Ok((guard, grid))
+}
+
+/// How a guard's patrol turned out.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    /// The guard walked off the map.
+    Exited(Vec<Guard>),
+    /// The guard got stuck walking in circles. The second field is
+    /// where in the trace the circle starts, and the guard goes back
+    /// there after the last state in the trace.
+    Looped(Vec<Guard>, usize),
+}
+
+impl Outcome {
+    /// Where the guard started each straight stretch of their patrol.
+    ///
+    /// This is where they started, followed by where they were
+    /// and which way they faced after every turn.
+    pub fn trace(&self) -> &[Guard] {
+        match self {
+            Outcome::Exited(trace) | Outcome::Looped(trace, _) => trace,
+        }
+    }
+
+    /// Every state the guard was in, one step at a time.
+    ///
+    /// The guard shows up twice wherever they turn, once for each
+    /// way they faced. A loop is only walked around once.
+    pub fn walk(&self, grid: &Grid<bool>) -> Vec<Guard> {
+        let trace = self.trace();
+        let ends = trace.iter().skip(1).map(|guard| Some(guard.position));
+        let last = match self {
+            Outcome::Exited(_) => None,
+            Outcome::Looped(trace, start) => Some(trace[*start].position),
+        };
+
+        let mut walk = Vec::new();
+        for (&start, end) in trace.iter().zip(ends.chain([last])) {
+            let mut guard = start;
+            walk.push(guard);
+            while Some(guard.position) != end {
+                match grid.step(guard.position, guard.direction) {
+                    Some(next) => guard.position = next,
+                    None => break,
+                }
+                walk.push(guard);
+            }
+        }
+
+        walk
+    }
+
+    /// Draw the map with the guard's patrol on it, as the puzzle does.
+    ///
+    /// The guard is shown where they started.
+    pub fn render(&self, grid: &Grid<bool>, extra: Option<GridIndex>) -> String {
+        render(grid, &self.trace()[0], &self.walk(grid), extra)
+    }
+
+    /// Replay the guard's patrol one step at a time, drawing a frame
+    /// with the path so far for every state they were in.
+    pub fn animate(
+        &self,
+        grid: &Grid<bool>,
+        extra: Option<GridIndex>,
+        recorder: &mut impl Recorder,
+    ) {
+        let walk = self.walk(grid);
+        for (step, guard) in walk.iter().enumerate() {
+            recorder.frame(&render(grid, guard, &walk[..=step], extra));
+        }
+    }
+}
+
+/// Draw the map with a path the guard took on it.
+///
+/// Obstacles are drawn as `#` and the `extra` obstacle as `O`. The
+/// path is drawn with `|` where the guard walked north or south, `-`
+/// where they walked east or west and `+` where they did both. The
+/// guard themself is drawn on top as `^`, `>`, `v` or `<`, depending
+/// on which way they're facing.
+pub fn render(
+    grid: &Grid<bool>,
+    guard: &Guard,
+    path: &[Guard],
+    extra: Option<GridIndex>,
+) -> String {
+    let cells = grid
+        .cell_iter()
+        .map(|&obstacle| if obstacle { '#' } else { '.' });
+    let mut picture = Grid::new(grid.width(), grid.height(), cells.collect());
+
+    for step in path {
+        let vertical = matches!(step.direction, Direction::North | Direction::South);
+        let cell = &mut picture[step.position];
+        *cell = match (*cell, vertical) {
+            ('.', true) | ('|', true) => '|',
+            ('.', false) | ('-', false) => '-',
+            _ => '+',
+        };
+    }
+
+    if let Some(extra) = extra {
+        picture[extra] = 'O';
+    }
+    picture[guard.position] = match guard.direction {
+        Direction::North => '^',
+        Direction::East => '>',
+        Direction::South => 'v',
+        Direction::West => '<',
+    };
+
+    picture.display(|&cell| cell).to_string()
+}
+
+/// Follow the guard's patrol, with an `extra` obstacle if there is one.
+pub fn simulate(guard: &Guard, grid: &Grid<bool>, extra: Option<GridIndex>) -> Outcome {
+    Jumps::new(grid).simulate(*guard, extra)
+}
+
+/// Find the amount of space the guard takes up.
+///
+/// The guard will move as far as they can in their current
+/// direction until they find an obstacle, turn right, and
+/// carry on until they leave the map.
+pub fn part_one(guard: &Guard, grid: &Grid<bool>) -> usize {
+    simulate(guard, grid, None)
+        .walk(grid)
+        .iter()
+        .map(|guard| guard.position)
+        .collect::<HashSet<_>>()
+        .len()
+}
+
+/// Find the number of points where a loop can
+/// be created in the guard's path.
+///
+/// An obstacle only changes anything if it's somewhere the
+/// guard would have walked, and the guard's path up until they
+/// first walk into it stays the same. So each candidate is tried
+/// by putting the guard back where they were just before first
+/// stepping there, and seeing whether they get stuck in a loop.
+pub fn part_two(guard: &Guard, grid: &Grid<bool>) -> usize {
+    let jumps = Jumps::new(grid);
+    let walk = jumps.simulate(*guard, None).walk(grid);
+    let mut tried = HashSet::from([guard.position]);
+
+    walk.windows(2)
+        .filter(|pair| tried.insert(pair[1].position))
+        .filter(|pair| {
+            let outcome = jumps.simulate(pair[0], Some(pair[1].position));
+            matches!(outcome, Outcome::Looped(..))
+        })
+        .count()
+}
+
+/// Where the guard ends up walking in a straight line from each cell.
+///
+/// For every cell and direction, this holds the last cell before
+/// the guard would bump into an obstacle, or [Option::None] if they
+/// would walk off the map instead. This means the guard can be
+/// moved a whole leg of their patrol at a time.
+#[derive(Clone, Debug)]
+struct Jumps {
+    grid: Grid<bool>,
+    stops: Grid<[Option<GridIndex>; 4]>,
+}
+
+impl Jumps {
+    fn new(grid: &Grid<bool>) -> Jumps {
+        let mut stops = Grid::new(grid.width(), grid.height(), vec![[None; 4]; grid.area()]);
+
+        let (width, height) = (grid.width(), grid.height());
+        for dir in Direction::ALL {
+            let mut sweep = |column, row| {
+                let idx = GridIndex::new(column, row);
+                stops[idx][dir as usize] = match grid.step(idx, dir) {
+                    Some(ahead) if grid[ahead] => Some(idx),
+                    Some(ahead) => stops[ahead][dir as usize],
+                    None => None,
+                };
+            };
+
+            // The cell ahead always has to be worked out first.
+            match dir {
+                Direction::North | Direction::West => {
+                    for row in 0..height {
+                        for column in 0..width {
+                            sweep(column, row);
+                        }
+                    }
+                }
+                Direction::South | Direction::East => {
+                    for row in (0..height).rev() {
+                        for column in (0..width).rev() {
+                            sweep(column, row);
+                        }
+                    }
+                }
+            }
+        }
+
+        Jumps {
+            grid: grid.clone(),
+            stops,
+        }
+    }
+
+    /// Walk the guard forward until they bump into something and turn,
+    /// giving [Option::None] if they walk off the map instead.
+    ///
+    /// The `extra` obstacle is treated as if it were on the map.
+    fn next(&self, guard: Guard, extra: Option<GridIndex>) -> Option<Guard> {
+        let dir = guard.direction;
+        let stop = self.stops[guard.position][dir as usize];
+
+        // The extra obstacle only matters if it's in the way.
+        let blocked = extra.filter(|&obstacle| {
+            steps_to(guard.position, dir, obstacle).is_some_and(|steps| {
+                steps > 0
+                    && stop.is_none_or(|stop| {
+                        steps_to(guard.position, dir, stop).is_some_and(|until| steps <= until)
+                    })
+            })
+        });
+        let stop = match blocked {
+            Some(obstacle) => self.grid.step(obstacle, dir.opposite()),
+            None => stop,
+        }?;
+
+        Some(Guard {
+            position: stop,
+            direction: dir.turn_right(),
+        })
+    }
+
+    /// Follow the guard's patrol a straight stretch at a time.
+    ///
+    /// The guard is stuck in a loop as soon as they turn somewhere
+    /// they've turned before, facing the same way.
+    fn simulate(&self, guard: Guard, extra: Option<GridIndex>) -> Outcome {
+        let mut trace = vec![guard];
+        let mut seen = HashMap::from([(guard, 0)]);
+
+        let mut guard = guard;
+        while let Some(next) = self.next(guard, extra) {
+            if let Some(&start) = seen.get(&next) {
+                return Outcome::Looped(trace, start);
+            }
+
+            seen.insert(next, trace.len());
+            trace.push(next);
+            guard = next;
+        }
+
+        Outcome::Exited(trace)
+    }
+}
+
+/// How many steps it takes to walk from `from` to `to` facing `dir`,
+/// if `to` is straight ahead at all. Staying put counts as zero steps.
+fn steps_to(from: GridIndex, dir: Direction, to: GridIndex) -> Option<usize> {
+    let (column, row) = dir.delta();
+    let across = (to.column() as isize - from.column() as isize) * column;
+    let down = (to.row() as isize - from.row() as isize) * row;
+
+    let on_line = match dir {
+        Direction::North | Direction::South => from.column() == to.column(),
+        Direction::East | Direction::West => from.row() == to.row(),
+    };
+    let steps = across + down;
+
+    (on_line && steps >= 0).then_some(steps as usize)
+}
+
+/// The solution to day 06.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Guard Gallivant";
+
+    type Input = (Guard, Grid<bool>);
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse_input(text: &str) -> Result<Self::Input, AdventError> {
+        parse_input(text)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne, AdventError> {
+        Ok(part_one(&input.0, &input.1))
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Option<Self::PartTwo>, AdventError> {
+        Ok(Some(part_two(&input.0, &input.1)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::fs::read_to_string;
+
+    use std::sync::LazyLock;
+
+    static INPUT: LazyLock<(Guard, Grid<bool>)> = LazyLock::new(|| {
+        let file = read_to_string("src/input/day06-test.txt").unwrap();
+
+        parse_input(&file).unwrap()
+    });
+
+    static TEST_CASE_1: &str = ".#....
+.....#
+...^..
+#.....
+....#.";
+
+    static TEST_CASE_2: &str = ".#..
+#..#
+....
+^...
+#...
+.#..";
+
+    #[test]
+    fn test_case() {
+        let (guard, grid) = parse_input(TEST_CASE_2).unwrap();
+
+        assert_eq!(part_two(&guard, &grid), 1);
+    }
+
+    #[test]
+    fn test_parse_input() {
+        let (guard, grid) = &*INPUT;
+
+        assert_eq!(guard.position, GridIndex::new(4, 6));
+
+        assert_eq!(grid.height(), 10, "Grid size incorrectly parsed");
+        assert_eq!(grid.width(), 10, "Grid size incorrectly parsed");
+        assert!(grid[(4, 0)], "Grid data incorrectly parsed");
+
+        assert!(matches!(parse_input("..\n.."), Err(AdventError::Data(_))));
+        assert!(matches!(parse_input(""), Err(AdventError::Data(_))));
+        let err = parse_input("^.\n.^").unwrap_err();
+        assert_eq!(err.location().map(|location| location.line), Some(2));
+    }
+
+    #[test]
+    fn test_part_one() {
+        let (guard, grid) = &*INPUT;
+
+        assert_eq!(part_one(guard, grid), 41);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let (guard, grid) = &*INPUT;
+
+        assert_eq!(part_two(guard, grid), 6);
+    }
+
+    #[test]
+    fn test_simulate() {
+        let (guard, grid) = &*INPUT;
+
+        let outcome = simulate(guard, grid, None);
+        let Outcome::Exited(trace) = &outcome else {
+            panic!("The guard should leave the map");
+        };
+        assert_eq!(trace.len(), 11);
+        assert_eq!(trace[0], *guard);
+        assert_eq!(
+            trace[1],
+            Guard {
+                position: GridIndex::new(4, 1),
+                direction: Direction::East,
+            }
+        );
+        assert_eq!(
+            trace[10],
+            Guard {
+                position: GridIndex::new(7, 7),
+                direction: Direction::South,
+            }
+        );
+
+        let walk = outcome.walk(grid);
+        assert_eq!(walk.first(), Some(guard));
+        assert_eq!(walk.last().unwrap().position, GridIndex::new(7, 9));
+    }
+
+    #[test]
+    fn test_simulate_loop() {
+        let (guard, grid) = &*INPUT;
+
+        // The puzzle's first example of a loop. Heading west along
+        // the guard's starting row, they bump into the new obstacle
+        // right next to where they started, and turn to face north
+        // again. So the whole patrol is one big loop.
+        let outcome = simulate(guard, grid, Some(GridIndex::new(3, 6)));
+        let Outcome::Looped(trace, start) = &outcome else {
+            panic!("(3,6) does not loop");
+        };
+        assert_eq!(trace.len(), 4);
+        assert_eq!(*start, 0);
+        assert_eq!(trace[*start], *guard);
+
+        // Here the guard walks a little way before getting stuck,
+        // and comes back to where they first turned south.
+        let (guard, grid) = parse_input(TEST_CASE_1).unwrap();
+        let outcome = simulate(&guard, &grid, Some(GridIndex::new(3, 0)));
+        let Outcome::Looped(trace, start) = &outcome else {
+            panic!("(3,0) does not loop");
+        };
+        assert_eq!(trace.len(), 6);
+        assert_eq!(*start, 2);
+        assert_eq!(
+            trace[*start],
+            Guard {
+                position: GridIndex::new(4, 1),
+                direction: Direction::South,
+            }
+        );
+    }
+
+    #[test]
+    fn test_render() {
+        let (guard, grid) = &*INPUT;
+        let extra = Some(GridIndex::new(3, 6));
+
+        // Straight out of the puzzle text.
+        let picture = "\
+....#.....
+....+---+#
+....|...|.
+..#.|...|.
+....|..#|.
+....|...|.
+.#.O^---+.
+........#.
+#.........
+......#...";
+        assert_eq!(simulate(guard, grid, extra).render(grid, extra), picture);
+
+        let walk = simulate(guard, grid, None).walk(grid);
+        let picture = render(grid, &walk[3], &walk[..4], None);
+        assert_eq!(picture.lines().nth(3), Some("..#.^....."));
+        assert_eq!(picture.lines().nth(4), Some("....|..#.."));
+    }
+
+    #[test]
+    fn test_animate() {
+        let (guard, grid) = &*INPUT;
+        let outcome = simulate(guard, grid, None);
+
+        let mut frames = Vec::new();
+        outcome.animate(grid, None, &mut frames);
+
+        assert_eq!(frames.len(), outcome.walk(grid).len());
+        assert_eq!(frames[0].lines().nth(6), Some(".#..^....."));
+        assert_eq!(frames[1].lines().nth(5), Some("....^....."));
+        assert_eq!(frames[1].lines().nth(6), Some(".#..|....."));
+        assert_eq!(frames.last().unwrap().lines().nth(9), Some("......#v.."));
+    }
+
+    #[test]
+    fn test_loops() {
+        let (guard, grid) = &*INPUT;
+        let loops = |extra| matches!(simulate(guard, grid, extra), Outcome::Looped(..));
+
+        assert!(!loops(None));
+        for (column, row) in [(3, 6), (6, 7), (7, 7), (1, 8), (3, 8), (7, 9)] {
+            assert!(
+                loops(Some(GridIndex::new(column, row))),
+                "({},{}) does not loop",
+                column,
+                row
+            );
+        }
+        assert!(!loops(Some(GridIndex::new(0, 0))));
+    }
+
+    #[test]
+    fn test_case_1() {
+        let (guard, grid) = parse_input(TEST_CASE_1).unwrap();
+
+        assert_eq!(part_one(&guard, &grid), 3);
+        assert_eq!(part_two(&guard, &grid), 1);
+    }
+
+    #[test]
+    fn test_stuck_from_the_start() {
+        // A guard stuck in a loop still only covers so many cells.
+        let (guard, grid) = parse_input(".#..\n.^.#\n....\n#...\n..#.").unwrap();
+
+        assert!(matches!(
+            simulate(&guard, &grid, None),
+            Outcome::Looped(_, 1)
+        ));
+        assert_eq!(part_one(&guard, &grid), 6);
+    }
+
+    #[test]
+    fn test_jumps() {
+        let (guard, grid) = &*INPUT;
+        let jumps = Jumps::new(grid);
+
+        let first = jumps.next(*guard, None).unwrap();
+        assert_eq!(first.position, GridIndex::new(4, 1));
+        assert_eq!(first.direction, Direction::East);
+
+        // An obstacle in the way cuts the walk short.
+        let blocked = jumps.next(*guard, Some(GridIndex::new(4, 3))).unwrap();
+        assert_eq!(blocked.position, GridIndex::new(4, 4));
+
+        // One behind the guard doesn't.
+        assert_eq!(jumps.next(*guard, Some(GridIndex::new(4, 8))), Some(first));
+    }
+}
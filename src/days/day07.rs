@@ -4,12 +4,10 @@
 //! engineers and now they can't fix the bridge! We need to
 //! do something about it so we can get back to work!
 
-use std::fs::read_to_string;
-
-use advent2024::AdventError;
+use crate::{parse, AdventError, Solution};
 
 #[derive(Clone, Debug, PartialEq, Eq)]
-struct Equation {
+pub struct Equation {
     total: u64,
     operands: Vec<u64>,
 }
@@ -19,33 +17,20 @@ struct Equation {
 /// An equation consists of a numeric total,
 /// followed by a colon, and a list of numbers
 /// to be used as operands.
-fn parse_input(file: &str) -> Result<Vec<Equation>, AdventError> {
-    file.lines()
-        .map(|line| {
-            let numbers = line.split_whitespace().collect::<Vec<_>>();
-            let total = numbers[0];
-            let operands = &numbers[1..];
-
-            let Some(total) = total.strip_suffix(":") else {
-                return Err(AdventError::Parse("Total missing suffix".to_string()));
-            };
-
-            let total = total
-                .parse::<u64>()
-                .map_err(|err| AdventError::Parse(format!("Error parsing total: {}", err)))?;
-
-            let operands = operands
-                .iter()
-                .map(|oper| {
-                    oper.parse::<u64>().map_err(|err| {
-                        AdventError::Parse(format!("Error parsing operand: {}", err))
-                    })
-                })
-                .collect::<Result<Vec<_>, AdventError>>()?;
-
-            Ok(Equation { total, operands })
-        })
-        .collect::<Result<Vec<Equation>, _>>()
+pub fn parse_input(file: &str) -> Result<Vec<Equation>, AdventError> {
+    parse::lines(file, |line| {
+        let Some((total, operands)) = line.split_once(":") else {
+            return Err(AdventError::syntax(file, line, "a total ending in `:`"));
+        };
+
+        let total = parse::number(file, total.trim())?;
+        let operands = parse::numbers(file, operands)?;
+        if operands.is_empty() {
+            return Err(AdventError::syntax(file, line, "at least one operand"));
+        }
+
+        Ok(Equation { total, operands })
+    })
 }
 
 /// Find the sum of all of the valid equations.
@@ -54,7 +39,7 @@ fn parse_input(file: &str) -> Result<Vec<Equation>, AdventError> {
 /// a series of multiplications or additions. Note that
 /// there is no order of operations here; all operations
 /// happen in a strictly left to right order.
-fn part_one(data: &[Equation]) -> u64 {
+pub fn part_one(data: &[Equation]) -> u64 {
     data.iter()
         .filter(|eq| {
             let mut totals = vec![eq.operands[0]];
@@ -71,7 +56,7 @@ fn part_one(data: &[Equation]) -> u64 {
         .sum()
 }
 
-fn part_two(data: &[Equation]) -> u64 {
+pub fn part_two(data: &[Equation]) -> u64 {
     data.iter()
         .filter(|eq| {
             let mut totals = vec![eq.operands[0]];
@@ -97,18 +82,35 @@ fn concatenate(a: u64, b: u64) -> u64 {
     a * magnitude + b
 }
 
-fn main() -> Result<(), AdventError> {
-    let file = read_to_string("src/input/day07.txt")?;
-    let data = parse_input(&file)?;
+/// The solution to day 07.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    const TITLE: &'static str = "Bridge Repair";
 
-    println!("Sum of possible equations is {}", part_one(&data));
-    println!("Sum of possible three-op equations is {}", part_two(&data));
-    Ok(())
+    type Input = Vec<Equation>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse_input(text: &str) -> Result<Self::Input, AdventError> {
+        parse_input(text)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne, AdventError> {
+        Ok(part_one(input))
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Option<Self::PartTwo>, AdventError> {
+        Ok(Some(part_two(input)))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::fs::read_to_string;
 
     use std::sync::LazyLock;
 
@@ -135,14 +137,14 @@ mod tests {
     fn test_part_one() {
         let data = &*INPUT;
 
-        assert_eq!(part_one(&data), 3749);
+        assert_eq!(part_one(data), 3749);
     }
 
     #[test]
     fn test_part_two() {
         let data = &*INPUT;
 
-        assert_eq!(part_two(&data), 11387);
+        assert_eq!(part_two(data), 11387);
     }
 
     #[test]
@@ -0,0 +1,141 @@
+//! # Day 08: Resident Collinearity
+//!
+//! The Easter Bunny is back at it again! We need to
+//! put a stop to his plan to sell terrible Easter
+//! chocolate for Christmas! (Wait a few months, pal!)
+
+use std::collections::HashSet;
+
+use crate::grid::GridExt;
+use crate::point::Point;
+use crate::{parse, AdventError, Solution};
+
+use itertools::Itertools;
+use simple_grid::Grid;
+
+/// Puzzle input consists of a grid of characters,
+/// representing a map with antennae.
+///
+/// Any non-period character represents an antennae
+/// sending a particular frequency.
+pub fn parse_input(file: &str) -> Result<Grid<char>, AdventError> {
+    parse::char_grid(file)
+}
+
+/// Find the number of antinodes on the map.
+///
+/// Two antinodes are created for every pair
+/// of antennae with the same character.
+pub fn part_one(data: &Grid<char>) -> usize {
+    let antennae = data
+        .indices()
+        .filter(|&idx| data[idx] != '.')
+        .filter_map(|idx| Some((data[idx], Point::from_index(idx)?)))
+        .into_group_map();
+
+    antennae
+        .values()
+        .flat_map(|nodes| {
+            nodes.iter().permutations(2).map(|pair| {
+                let one = *pair[0];
+                let two = *pair[1];
+
+                // The antinode is as far past `two` as `two` is from `one`.
+                two + (two - one)
+            })
+        })
+        .filter_map(|point| data.index_of(point))
+        .collect::<HashSet<_>>()
+        .len()
+}
+
+/// Find the number of resonant antinodes on the map.
+///
+/// It turns out that thanks to resonant harmonics,
+/// two antennae can produce an _infinite_ number of
+/// antinodes, not just one each.
+pub fn part_two(data: &Grid<char>) -> usize {
+    let antennae = data
+        .indices()
+        .filter(|&idx| data[idx] != '.')
+        .filter_map(|idx| Some((data[idx], Point::from_index(idx)?)))
+        .into_group_map();
+
+    antennae
+        .values()
+        .flat_map(|nodes| {
+            nodes.iter().permutations(2).flat_map(|pair| {
+                let one = *pair[0];
+                let two = *pair[1];
+                let step = two - one;
+
+                // Keep stepping away from `one` until we leave the map.
+                (0..)
+                    .map(move |count| data.index_of(two + step * count))
+                    .take_while(Option::is_some)
+                    .flatten()
+            })
+        })
+        .collect::<HashSet<_>>()
+        .len()
+}
+
+/// The solution to day 08.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    const TITLE: &'static str = "Resident Collinearity";
+
+    type Input = Grid<char>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse_input(text: &str) -> Result<Self::Input, AdventError> {
+        parse_input(text)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne, AdventError> {
+        Ok(part_one(input))
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Option<Self::PartTwo>, AdventError> {
+        Ok(Some(part_two(input)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::fs::read_to_string;
+
+    use std::sync::LazyLock;
+
+    static INPUT: LazyLock<Grid<char>> = LazyLock::new(|| {
+        let file = read_to_string("src/input/day08-test.txt").unwrap();
+
+        parse_input(&file).unwrap()
+    });
+
+    #[test]
+    fn test_parse_input() {
+        let data = &*INPUT;
+
+        assert_eq!(data.get((8, 1)), Some(&'0'));
+    }
+
+    #[test]
+    fn test_part_one() {
+        let data = &*INPUT;
+
+        assert_eq!(part_one(data), 14);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let data = &*INPUT;
+
+        assert_eq!(part_two(data), 34);
+    }
+}
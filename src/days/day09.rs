@@ -0,0 +1,440 @@
+//! Day 09: Disk Fragmenter
+//!
+//! We need to do some file system clean up here. If only the
+//! computer didn't have the most arcane diagnostic information
+//! this side of the `tar` command...
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::fmt;
+use std::iter::repeat_n;
+
+use crate::animate::{Discard, Recorder};
+use crate::{AdventError, Part, Solution};
+use itertools::Itertools;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Space {
+    size: usize,
+    index: Option<usize>,
+}
+
+/// Input consists of a series of digits, representing
+/// the disk map of this bizarre computer setup.
+pub fn parse_input(file: &str) -> Result<Vec<Space>, AdventError> {
+    let digits = file.trim();
+    let digit = |(offset, ch): (usize, char)| {
+        ch.to_digit(10).map(|digit| digit as usize).ok_or_else(|| {
+            AdventError::syntax(file, &digits[offset..offset + ch.len_utf8()], "a digit")
+        })
+    };
+
+    let chars = digits.char_indices().collect::<Vec<_>>();
+    chars
+        .chunks(2)
+        .enumerate()
+        .map(|(idx, pair)| {
+            let one = digit(pair[0])?;
+            let two = match pair.get(1) {
+                Some(&two) => digit(two)?,
+                None => 0,
+            };
+
+            Ok([
+                Space {
+                    size: one,
+                    index: Some(idx),
+                },
+                Space {
+                    size: two,
+                    index: None,
+                },
+            ])
+        })
+        .flatten_ok()
+        .collect()
+}
+
+/// Find the checksum of the filesystem.
+///
+/// First, defragment the computer by removing as much
+/// space as possible.
+pub fn part_one(data: &[Space]) -> usize {
+    let disk = defragment(data, &mut Discard);
+
+    disk.iter()
+        .enumerate()
+        .filter_map(|(idx, file)| file.map(|id| id * idx))
+        .sum()
+}
+
+fn defragment(data: &[Space], recorder: &mut impl Recorder) -> Vec<Option<usize>> {
+    let mut disk = data
+        .iter()
+        .flat_map(|file| repeat_n(file.index, file.size))
+        .collect::<Vec<_>>();
+    recorder.frame(&Blocks(&disk));
+    // Everything before `start` is files, and everything
+    // from `end` onwards is free space.
+    let mut start = 0;
+    let mut end = disk.len();
+
+    while start < end {
+        if disk[start].is_some() {
+            start += 1;
+        } else if disk[end - 1].is_none() {
+            end -= 1;
+        } else {
+            // Move the last file block into the first free space.
+            disk.swap(start, end - 1);
+            recorder.frame(&Blocks(&disk));
+            start += 1;
+            end -= 1;
+        }
+    }
+    disk
+}
+
+/// Find the checksum of the defragmented filesystem.
+///
+/// This time files are moved whole, so fragmentation
+/// is avoided at the cost of some wasted space.
+pub fn part_two(data: &[Space]) -> usize {
+    let disk = clean_space(data, &mut Discard);
+
+    let mut position = 0;
+    let mut checksum = 0;
+    for space in disk {
+        if let Some(id) = space.index {
+            checksum += id * (position..position + space.size).sum::<usize>();
+        }
+        position += space.size;
+    }
+
+    checksum
+}
+
+/// Compact the disk by moving whole files.
+///
+/// Each file is moved once, in decreasing order of ID, to the leftmost
+/// gap that can fit it, as long as that gap is left of the file.
+///
+/// Gaps are never more than nine blocks long, so every gap sits in a heap
+/// with the others of its size. The leftmost gap that fits a file is then
+/// the leftmost of the heads of the heaps for sizes at least as big, and a
+/// gap only partly filled goes back into the heap for its new size.
+fn clean_space(data: &[Space], recorder: &mut impl Recorder) -> Vec<Space> {
+    let mut gaps = vec![BinaryHeap::new(); 10];
+    let mut files = Vec::new();
+
+    let mut position = 0;
+    for space in data {
+        match space.index {
+            Some(id) => files.push((position, space.size, id)),
+            None if space.size > 0 => gaps[space.size].push(Reverse(position)),
+            None => {}
+        }
+        position += space.size;
+    }
+    let length = position;
+    recorder.frame(&Layout {
+        files: &files,
+        length,
+    });
+
+    for file in (0..files.len()).rev() {
+        let (position, size, _) = files[file];
+        let gap = (size..gaps.len())
+            .filter_map(|gap_size| gaps[gap_size].peek().map(|&Reverse(at)| (at, gap_size)))
+            .filter(|&(at, _)| at < position)
+            .min();
+
+        if let Some((at, gap_size)) = gap {
+            gaps[gap_size].pop();
+            if gap_size > size {
+                gaps[gap_size - size].push(Reverse(at + size));
+            }
+            files[file].0 = at;
+            recorder.frame(&Layout {
+                files: &files,
+                length,
+            });
+        }
+    }
+
+    // Lay the files back out in order, with gaps wherever they left space.
+    files.sort_unstable();
+    let mut disk = Vec::with_capacity(files.len() * 2);
+    let mut position = 0;
+    for (at, size, id) in files {
+        if at > position {
+            disk.push(Space {
+                size: at - position,
+                index: None,
+            });
+        }
+        disk.push(Space {
+            size,
+            index: Some(id),
+        });
+        position = at + size;
+    }
+    if length > position {
+        disk.push(Space {
+            size: length - position,
+            index: None,
+        });
+    }
+
+    disk
+}
+
+/// Replay how the disk gets compacted for one of the parts, drawing
+/// a frame for the disk as it starts and after every move.
+///
+/// Each block shows the last digit of its file's ID, or `.` if
+/// it's free, like the puzzle does.
+pub fn animate(data: &[Space], part: Part, recorder: &mut impl Recorder) {
+    match part {
+        Part::One => {
+            defragment(data, recorder);
+        }
+        Part::Two => {
+            clean_space(data, recorder);
+        }
+    }
+}
+
+/// The disk drawn a block at a time.
+struct Blocks<'a>(&'a [Option<usize>]);
+
+impl fmt::Display for Blocks<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for block in self.0 {
+            let block = match block {
+                Some(id) => char::from(b'0' + (id % 10) as u8),
+                None => '.',
+            };
+            write!(f, "{}", block)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The disk drawn from where each file is, as `(position, size, id)`.
+struct Layout<'a> {
+    files: &'a [(usize, usize, usize)],
+    length: usize,
+}
+
+impl fmt::Display for Layout<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut disk = vec![None; self.length];
+        for &(at, size, id) in self.files {
+            disk[at..at + size].fill(Some(id));
+        }
+
+        Blocks(&disk).fmt(f)
+    }
+}
+
+/// The solution to day 09.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    const TITLE: &'static str = "Disk Fragmenter";
+
+    type Input = Vec<Space>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse_input(text: &str) -> Result<Self::Input, AdventError> {
+        parse_input(text)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne, AdventError> {
+        Ok(part_one(input))
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Option<Self::PartTwo>, AdventError> {
+        Ok(Some(part_two(input)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::fs::read_to_string;
+
+    use std::sync::LazyLock;
+
+    static INPUT: LazyLock<Vec<Space>> = LazyLock::new(|| {
+        let file = read_to_string("src/input/day09-test.txt").unwrap();
+
+        parse_input(&file).unwrap()
+    });
+
+    #[test]
+    fn test_parse_input() {
+        let data = parse_input("12345").unwrap();
+
+        assert_eq!(
+            data,
+            vec![
+                Space {
+                    size: 1,
+                    index: Some(0)
+                },
+                Space {
+                    size: 2,
+                    index: None
+                },
+                Space {
+                    size: 3,
+                    index: Some(1)
+                },
+                Space {
+                    size: 4,
+                    index: None
+                },
+                Space {
+                    size: 5,
+                    index: Some(2)
+                },
+                Space {
+                    size: 0,
+                    index: None
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_part_one() {
+        let data = &*INPUT;
+
+        assert_eq!(part_one(data), 1928);
+    }
+
+    #[test]
+    fn test_defragment() {
+        let data = &*INPUT;
+
+        let disk = defragment(data, &mut Discard)
+            .iter()
+            .filter_map(|file| *file)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            disk,
+            vec![
+                0, 0, 9, 9, 8, 1, 1, 1, 8, 8, 8, 2, 7, 7, 7, 3, 3, 3, 6, 4, 4, 6, 5, 5, 5, 5, 6, 6
+            ]
+        )
+    }
+
+    #[test]
+    fn test_nothing_to_move() {
+        // No disk at all, no free space, and free space only at the end.
+        for map in ["", "2", "12"] {
+            let data = parse_input(map).unwrap();
+
+            assert_eq!(part_one(&data), 0, "Part one of {:?}", map);
+            assert_eq!(part_two(&data), 0, "Part two of {:?}", map);
+        }
+        assert_eq!(part_one(&parse_input("123").unwrap()), 6);
+    }
+
+    #[test]
+    fn test_clean_space() {
+        let data = &*INPUT;
+
+        let disk = clean_space(data, &mut Discard);
+
+        let disk = disk
+            .iter()
+            .filter_map(|file| file.index)
+            .collect::<Vec<_>>();
+
+        assert_eq!(disk, vec![0, 9, 2, 1, 7, 4, 3, 5, 6, 8]);
+    }
+
+    #[test]
+    fn test_animate() {
+        let data = &*INPUT;
+
+        // Straight out of the puzzle text.
+        let mut frames = Vec::new();
+        animate(data, Part::Two, &mut frames);
+        assert_eq!(
+            frames,
+            vec![
+                "00...111...2...333.44.5555.6666.777.888899",
+                "0099.111...2...333.44.5555.6666.777.8888..",
+                "0099.1117772...333.44.5555.6666.....8888..",
+                "0099.111777244.333....5555.6666.....8888..",
+                "00992111777.44.333....5555.6666.....8888..",
+            ]
+        );
+
+        let mut frames = Vec::new();
+        animate(&parse_input("12345").unwrap(), Part::One, &mut frames);
+        assert_eq!(frames[0], "0..111....22222");
+        assert_eq!(frames[1], "02.111....2222.");
+        assert_eq!(frames.last().unwrap(), "022111222......");
+    }
+
+    #[test]
+    fn test_part_two() {
+        let data = &*INPUT;
+
+        assert_eq!(part_two(data), 2858);
+    }
+
+    /// Move files a block at a time, exactly as the puzzle describes.
+    fn naive_part_two(data: &[Space]) -> usize {
+        let mut disk = data
+            .iter()
+            .flat_map(|file| repeat_n(file.index, file.size))
+            .collect::<Vec<_>>();
+        let files = data.iter().filter(|space| space.index.is_some()).count();
+
+        for id in (0..files).rev() {
+            let start = disk.iter().position(|&block| block == Some(id)).unwrap();
+            let size = disk.iter().filter(|&&block| block == Some(id)).count();
+            let gap = (0..start).find(|&at| disk[at..at + size].iter().all(Option::is_none));
+
+            if let Some(at) = gap {
+                disk[at..at + size].fill(Some(id));
+                disk[start..start + size].fill(None);
+            }
+        }
+
+        disk.iter()
+            .enumerate()
+            .filter_map(|(idx, file)| file.map(|id| id * idx))
+            .sum()
+    }
+
+    #[test]
+    fn test_part_two_matches_naive() {
+        // A cheap pseudo-random disk map, so there's plenty to move.
+        // Real disk maps never have empty files, so neither does this.
+        let mut seed = 12345_u64;
+        let map = (0..2000)
+            .map(|idx| {
+                seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1);
+                let digit = (seed >> 33) as u8 % 10;
+                match idx % 2 {
+                    0 => char::from(b'1' + digit % 9),
+                    _ => char::from(b'0' + digit),
+                }
+            })
+            .collect::<String>();
+        let data = parse_input(&map).unwrap();
+
+        assert_eq!(part_two(&data), naive_part_two(&data));
+    }
+}
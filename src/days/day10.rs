@@ -0,0 +1,170 @@
+//! # Day 10: Hoof It
+//!
+//! The Lava Island has a thriving hiking culture apparently!
+//! Unfortunately, the obvious happened to the documentation
+//! and now we have to fix it.
+
+use std::collections::HashMap;
+
+use crate::search::{self, Flow};
+use crate::{parse, AdventError, Solution};
+
+use simple_grid::{Grid, GridIndex};
+
+/// Puzzle input consists of a grid of numbers,
+/// representing a topological map.
+pub fn parse_input(file: &str) -> Result<Grid<u32>, AdventError> {
+    parse::grid(file, |ch, _| ch.to_digit(10).ok_or("a digit"))
+}
+
+/// Find all of the trails on the map.
+///
+/// A trail is defined as a contiguous sequence of the digits
+/// zero through nine cardinally adjacent to one another.
+pub fn part_one(data: &Grid<u32>) -> usize {
+    let zeroes = data.indices().filter(|&idx| data[idx] == 0);
+    zeroes.map(|idx| find_trail(data, idx)).sum()
+}
+
+/// Given a spot on the map, find the number of trails
+/// going from it.
+///
+/// # Panics
+///
+/// This assumes that the index given corresponds to the
+/// start of a trail, i.e. that it represents a `0` in the grid.
+/// It will panic if this assumption is violated.
+fn find_trail(data: &Grid<u32>, idx: GridIndex) -> usize {
+    assert!(data[idx] == 0, "Invalid trailhead");
+    let explored = search::bfs(idx, |&idx| uphill(data, idx), |_| Flow::Continue);
+
+    explored
+        .visited()
+        .iter()
+        .filter(|&&idx| data[idx] == 9)
+        .count()
+}
+
+/// The cells next to `idx` that are exactly one step higher.
+fn uphill(data: &Grid<u32>, idx: GridIndex) -> impl Iterator<Item = GridIndex> + '_ {
+    search::cardinal(data, idx).filter(move |&next| data[next] == data[idx] + 1)
+}
+
+/// Find the number of unique trails on the map.
+pub fn part_two(data: &Grid<u32>) -> usize {
+    let zeroes = data.indices().filter(|&idx| data[idx] == 0);
+    zeroes.map(|idx| find_paths(data, idx)).sum()
+}
+
+/// Given a trailhead, find the number of trails leading off from it.
+///
+/// # Panics
+///
+/// Panics if `idx` is not a trailhead, i.e. `data[idx] != 0`.
+fn find_paths(data: &Grid<u32>, idx: GridIndex) -> usize {
+    assert!(data[idx] == 0, "Invalid trailhead");
+    let explored = search::bfs(idx, |&idx| uphill(data, idx), |_| Flow::Continue);
+
+    // Every step goes up by one, so cells are visited in order of
+    // height and all the ways into a cell are counted before it is
+    // visited itself.
+    let mut paths = HashMap::from([(idx, 1)]);
+    for &idx in explored.visited() {
+        let count = paths[&idx];
+        for next in uphill(data, idx) {
+            *paths.entry(next).or_default() += count;
+        }
+    }
+
+    explored
+        .visited()
+        .iter()
+        .filter(|&&idx| data[idx] == 9)
+        .map(|idx| paths[idx])
+        .sum()
+}
+
+/// The solution to day 10.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    const TITLE: &'static str = "Hoof It";
+
+    type Input = Grid<u32>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse_input(text: &str) -> Result<Self::Input, AdventError> {
+        parse_input(text)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne, AdventError> {
+        Ok(part_one(input))
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Option<Self::PartTwo>, AdventError> {
+        Ok(Some(part_two(input)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_grid_eq;
+    use std::fs::read_to_string;
+
+    use std::sync::LazyLock;
+
+    static INPUT: LazyLock<Grid<u32>> = LazyLock::new(|| {
+        let file = read_to_string("src/input/day10-test.txt").unwrap();
+
+        parse_input(&file).unwrap()
+    });
+
+    #[test]
+    fn test_parse_input() {
+        let data = &*INPUT;
+
+        let row = data.row_iter(0).collect::<Vec<_>>();
+
+        assert_eq!(row, vec![&8, &9, &0, &1, &0, &1, &2, &3]);
+
+        let grid = parse_input("0123\n9876").unwrap();
+
+        assert_grid_eq!(grid, Grid::new(4, 2, vec![0, 1, 2, 3, 9, 8, 7, 6]));
+    }
+
+    #[test]
+    fn test_part_one() {
+        let data = &*INPUT;
+
+        assert_eq!(part_one(data), 36);
+    }
+
+    #[test]
+    fn test_find_trail() {
+        let data = &*INPUT;
+
+        let index = GridIndex::new(2, 0);
+
+        assert_eq!(find_trail(data, index), 5);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let data = &*INPUT;
+
+        assert_eq!(part_two(data), 81);
+    }
+
+    #[test]
+    fn test_find_paths() {
+        let data = &*INPUT;
+
+        let index = GridIndex::new(2, 0);
+
+        assert_eq!(find_paths(data, index), 20);
+    }
+}
@@ -0,0 +1,461 @@
+//! Day 11: Plutonian Pebbles
+//!
+//! We're off to Pluto to inspect some weird rocks!
+
+use std::collections::HashMap;
+
+use crate::{parse, AdventError, Solution};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Stone(u64);
+
+impl Stone {
+    /// Find the stones this one turns into after a blink.
+    ///
+    /// This fails if the number on the new stone is too big to engrave.
+    fn blink(&self, rules: &Rules) -> Result<Vec<Stone>, AdventError> {
+        match rules.0.iter().find(|rule| rule.when.matches(self.0)) {
+            Some(rule) => rule.then.apply(self.0),
+            None => Ok(vec![*self]),
+        }
+    }
+
+    /// How many digits are engraved on this stone.
+    fn digits(&self) -> u32 {
+        self.0.checked_ilog10().map_or(1, |power| power + 1)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// Which stones a rule applies to.
+pub enum Condition {
+    /// Stones engraved with exactly this number.
+    Is(u64),
+    /// Stones whose number of digits is a multiple of this.
+    DigitsDivisibleBy(u32),
+    /// Every stone.
+    Always,
+}
+
+impl Condition {
+    fn matches(&self, number: u64) -> bool {
+        match *self {
+            Condition::Is(value) => number == value,
+            Condition::DigitsDivisibleBy(divisor) => Stone(number).digits().is_multiple_of(divisor),
+            Condition::Always => true,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// What happens to a stone when a rule applies to it.
+pub enum Transform {
+    /// Replace the stone with one engraved with this number.
+    Become(u64),
+    /// Split the digits evenly between this many new stones.
+    SplitInto(u32),
+    /// Multiply the number on the stone by this.
+    MultiplyBy(u64),
+}
+
+impl Transform {
+    fn apply(&self, number: u64) -> Result<Vec<Stone>, AdventError> {
+        match *self {
+            Transform::Become(value) => Ok(vec![Stone(value)]),
+            Transform::SplitInto(parts) => {
+                let power = Stone(number)
+                    .digits()
+                    .checked_div(parts)
+                    .and_then(|digits| 10u64.checked_pow(digits))
+                    .ok_or_else(|| {
+                        AdventError::Data(format!(
+                            "Stone {} can't be split into {} parts",
+                            number, parts
+                        ))
+                    })?;
+                let mut stones = (0..parts)
+                    .scan(number, |rest, _| {
+                        let stone = Stone(*rest % power);
+                        *rest /= power;
+                        Some(stone)
+                    })
+                    .collect::<Vec<_>>();
+                stones.reverse();
+                Ok(stones)
+            }
+            Transform::MultiplyBy(factor) => {
+                let number = number.checked_mul(factor).ok_or_else(|| {
+                    AdventError::Data(format!("Stone {} grew too large to multiply", number))
+                })?;
+                Ok(vec![Stone(number)])
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// A single rule of how stones change.
+pub struct Rule {
+    pub when: Condition,
+    pub then: Transform,
+}
+
+impl Rule {
+    /// Check that this rule makes sense, describing what
+    /// was expected instead if it doesn't.
+    fn check(&self) -> Result<(), String> {
+        match (self.when, self.then) {
+            (Condition::DigitsDivisibleBy(0), _) => {
+                Err("digits divisible by a number above zero".to_string())
+            }
+            (_, Transform::SplitInto(0)) => Err("a split into at least one part".to_string()),
+            // Splitting only makes sense if the digits divide evenly.
+            (Condition::DigitsDivisibleBy(divisor), Transform::SplitInto(parts))
+                if divisor.is_multiple_of(parts) =>
+            {
+                Ok(())
+            }
+            (_, Transform::SplitInto(parts)) => {
+                Err(format!("a condition on digits divisible by {}", parts))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+/// The rules of how stones change when we blink.
+///
+/// Only the first rule that applies to a stone is used,
+/// and a stone no rule applies to stays as it is.
+pub struct Rules(Vec<Rule>);
+
+impl Default for Rules {
+    /// The rules the puzzle gives.
+    fn default() -> Self {
+        Rules(vec![
+            Rule {
+                when: Condition::Is(0),
+                then: Transform::Become(1),
+            },
+            Rule {
+                when: Condition::DigitsDivisibleBy(2),
+                then: Transform::SplitInto(2),
+            },
+            Rule {
+                when: Condition::Always,
+                then: Transform::MultiplyBy(2024),
+            },
+        ])
+    }
+}
+
+impl Rules {
+    /// Put together a list of rules, in order.
+    ///
+    /// This fails if any rule doesn't make sense, like splitting
+    /// a stone's digits into a number of parts they might not
+    /// divide evenly into.
+    pub fn new(rules: Vec<Rule>) -> Result<Rules, AdventError> {
+        for rule in &rules {
+            rule.check().map_err(|expected| {
+                AdventError::Data(format!("Invalid rule {:?}: expected {}", rule, expected))
+            })?;
+        }
+
+        Ok(Rules(rules))
+    }
+
+    /// Parse a list of rules, one per line, in order.
+    ///
+    /// The puzzle's own rules would be written like so,
+    /// and `#` starts a comment:
+    ///
+    /// ```notrust
+    /// is 0 => become 1
+    /// digits divisible by 2 => split into 2
+    /// always => multiply by 2024
+    /// ```
+    pub fn parse(text: &str) -> Result<Rules, AdventError> {
+        let lines = text
+            .lines()
+            .map(|line| {
+                line.split_once('#')
+                    .map_or(line, |(line, _comment)| line)
+                    .trim()
+            })
+            .filter(|line| !line.is_empty());
+
+        let mut rules = Vec::new();
+        for line in lines {
+            let (when, then) = line
+                .split_once("=>")
+                .ok_or_else(|| AdventError::syntax(text, line, "`<condition> => <transform>`"))?;
+            let (when, then) = (when.trim(), then.trim());
+
+            let when = if when == "always" {
+                Condition::Always
+            } else if let Some(value) = when.strip_prefix("is ") {
+                Condition::Is(parse::number(text, value.trim())?)
+            } else if let Some(divisor) = when.strip_prefix("digits divisible by ") {
+                Condition::DigitsDivisibleBy(positive(text, divisor.trim())?)
+            } else {
+                return Err(AdventError::syntax(
+                    text,
+                    when,
+                    "`always`, `is <n>` or `digits divisible by <n>`",
+                ));
+            };
+
+            let then = if let Some(value) = then.strip_prefix("become ") {
+                Transform::Become(parse::number(text, value.trim())?)
+            } else if let Some(parts) = then.strip_prefix("split into ") {
+                Transform::SplitInto(positive(text, parts.trim())?)
+            } else if let Some(factor) = then.strip_prefix("multiply by ") {
+                Transform::MultiplyBy(parse::number(text, factor.trim())?)
+            } else {
+                return Err(AdventError::syntax(
+                    text,
+                    then,
+                    "`become <n>`, `split into <n>` or `multiply by <n>`",
+                ));
+            };
+
+            let rule = Rule { when, then };
+            rule.check()
+                .map_err(|expected| AdventError::syntax(text, line, expected))?;
+            rules.push(rule);
+        }
+
+        Rules::new(rules)
+    }
+}
+
+/// Parse a number that has to be at least one.
+fn positive(text: &str, snippet: &str) -> Result<u32, AdventError> {
+    match parse::number(text, snippet)? {
+        0 => Err(AdventError::syntax(text, snippet, "a number above zero")),
+        number => Ok(number),
+    }
+}
+
+/// Input consists of a series of numbers, separated by spaces.
+pub fn parse_input(file: &str) -> Result<Vec<Stone>, AdventError> {
+    let stones = parse::numbers(file, file)?;
+
+    Ok(stones.into_iter().map(Stone).collect())
+}
+
+/// Count the number of stones after 25 blinks.
+///
+/// This fails if any stone grows too big to engrave.
+pub fn part_one(data: &[Stone]) -> Result<u128, AdventError> {
+    count_stones(data, 25)
+}
+
+/// Count the number of stones after 75 blinks.
+///
+/// This is the inevitable "learn to memoize" challenge, wherein the part
+/// two ups a limit to the point of being nigh unusable unless you cheat.
+pub fn part_two(data: &[Stone]) -> Result<u128, AdventError> {
+    count_stones(data, 75)
+}
+
+/// Count the number of stones after any number of blinks.
+///
+/// Stones never affect one another, and plenty of them share numbers,
+/// so only how many stones have each number needs tracking. This fails
+/// if a stone's number or the count of stones gets too big.
+pub fn count_stones(stones: &[Stone], blinks: usize) -> Result<u128, AdventError> {
+    count_stones_with(stones, blinks, &Rules::default())
+}
+
+/// Count the number of stones after any number of blinks,
+/// with a different set of rules to the puzzle's.
+pub fn count_stones_with(
+    stones: &[Stone],
+    blinks: usize,
+    rules: &Rules,
+) -> Result<u128, AdventError> {
+    // This keeps count of how many times a specific number is on a stone.
+    let mut stone_map = HashMap::new();
+    for &stone in stones {
+        *stone_map.entry(stone).or_insert(0) += 1;
+    }
+
+    for _ in 0..blinks {
+        let mut new_map = HashMap::new();
+        for (stone, &count) in stone_map.iter() {
+            for stone in stone.blink(rules)? {
+                let total: &mut u128 = new_map.entry(stone).or_insert(0);
+                *total = total.checked_add(count).ok_or_else(too_many)?;
+            }
+        }
+
+        stone_map = new_map;
+    }
+
+    stone_map
+        .values()
+        .try_fold(0u128, |total, &count| total.checked_add(count))
+        .ok_or_else(too_many)
+}
+
+fn too_many() -> AdventError {
+    AdventError::Data("Too many stones to count".to_string())
+}
+
+/// The solution to day 11.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    const TITLE: &'static str = "Plutonian Pebbles";
+
+    type Input = Vec<Stone>;
+    type PartOne = u128;
+    type PartTwo = u128;
+
+    fn parse_input(text: &str) -> Result<Self::Input, AdventError> {
+        parse_input(text)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne, AdventError> {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Option<Self::PartTwo>, AdventError> {
+        part_two(input).map(Some)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Blink one stone at a time, the way the puzzle describes.
+    fn blink_all(stones: &[Stone], blinks: usize) -> Vec<Stone> {
+        (0..blinks).fold(stones.to_vec(), |stones, _| {
+            stones
+                .iter()
+                .flat_map(|stone| stone.blink(&Rules::default()).unwrap())
+                .collect()
+        })
+    }
+
+    #[test]
+    fn test_parse_input() {
+        assert_eq!(
+            parse_input("125 17\n").unwrap(),
+            vec![Stone(125), Stone(17)]
+        );
+
+        assert_eq!(
+            parse_input("1234567890123 2").unwrap(),
+            vec![Stone(1234567890123), Stone(2)]
+        );
+    }
+
+    #[test]
+    fn test_blink() {
+        let rules = Rules::default();
+
+        assert_eq!(Stone(0).blink(&rules).unwrap(), vec![Stone(1)]);
+
+        assert_eq!(Stone(1).blink(&rules).unwrap(), vec![Stone(2024)]);
+
+        assert_eq!(Stone(10).blink(&rules).unwrap(), vec![Stone(1), Stone(0)]);
+
+        assert_eq!(
+            Stone(1000).blink(&rules).unwrap(),
+            vec![Stone(10), Stone(0)]
+        );
+
+        assert!(Stone(u64::MAX / 1000).blink(&rules).is_err());
+    }
+
+    #[test]
+    fn test_parse_rules() {
+        let rules = "\
+# The puzzle's own rules.
+is 0 => become 1
+digits divisible by 2 => split into 2
+always => multiply by 2024
+";
+        assert_eq!(Rules::parse(rules).unwrap(), Rules::default());
+
+        let err = Rules::parse("is 0 => become 1\nsometimes => become 2").unwrap_err();
+        assert_eq!(err.location().map(|location| location.line), Some(2));
+        assert!(Rules::parse("always => split into 2").is_err());
+        assert!(Rules::parse("digits divisible by 0 => become 1").is_err());
+    }
+
+    #[test]
+    fn test_new_rules() {
+        let rule = |when, then| Rules::new(vec![Rule { when, then }]);
+
+        assert!(rule(Condition::DigitsDivisibleBy(4), Transform::SplitInto(2)).is_ok());
+        assert!(rule(Condition::DigitsDivisibleBy(2), Transform::SplitInto(0)).is_err());
+        assert!(rule(Condition::DigitsDivisibleBy(0), Transform::Become(1)).is_err());
+        assert!(rule(Condition::Always, Transform::SplitInto(2)).is_err());
+        assert!(Transform::SplitInto(0).apply(1234).is_err());
+    }
+
+    #[test]
+    fn test_split_overflow() {
+        let rules = Rules::parse("digits divisible by 1 => split into 1").unwrap();
+
+        assert_eq!(Stone(1234).blink(&rules).unwrap(), vec![Stone(1234)]);
+        // 10^20 doesn't fit, even though this 20 digit stone does.
+        assert!(Stone(10_000_000_000_000_000_000).blink(&rules).is_err());
+    }
+
+    #[test]
+    fn test_variant_rules() {
+        let rules = Rules::parse(
+            "digits divisible by 3 => split into 3\nis 7 => become 0\nalways => multiply by 3",
+        )
+        .unwrap();
+
+        assert_eq!(
+            Stone(123456).blink(&rules).unwrap(),
+            vec![Stone(12), Stone(34), Stone(56)]
+        );
+        assert_eq!(Stone(7).blink(&rules).unwrap(), vec![Stone(0)]);
+        assert_eq!(Stone(0).blink(&rules).unwrap(), vec![Stone(0)]);
+        assert_eq!(count_stones_with(&[Stone(111)], 2, &rules).unwrap(), 3);
+    }
+
+    #[test]
+    fn test_part_one() {
+        let data = [Stone(125), Stone(17)];
+
+        assert_eq!(part_one(&data).unwrap(), 55312);
+
+        // Nineteen digits fit, but can't be multiplied by 2024.
+        assert!(part_one(&[Stone(9_999_999_999_999_999_999)]).is_err());
+    }
+
+    #[test]
+    fn test_part_two() {
+        let data = [Stone(125), Stone(17)];
+
+        assert_eq!(part_two(&data).unwrap(), 65601038650482);
+    }
+
+    #[test]
+    fn test_count_stones() {
+        let data = [Stone(125), Stone(17)];
+
+        assert_eq!(count_stones(&data, 6).unwrap(), 22);
+        for blinks in 0..=25 {
+            assert_eq!(
+                count_stones(&data, blinks).unwrap(),
+                blink_all(&data, blinks).len() as u128,
+                "Counts differ after {} blinks",
+                blinks
+            );
+        }
+    }
+}
@@ -0,0 +1,160 @@
+//! Day 12: Garden Groups
+//!
+//! Some Elves want some help with their garden.
+//! They don't know how much budget they're gonna
+//! need for their complicated garden plots. Oh boy...
+
+use crate::components::{self, Component};
+use crate::{parse, AdventError, Solution};
+
+use simple_grid::Grid;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Plot(Component<char>);
+
+impl Plot {
+    /// Find the area of this plot.
+    ///
+    /// Area is defined as the number of cells
+    /// this plot contains.
+    fn area(&self) -> usize {
+        self.0.area()
+    }
+
+    /// Find the perimeter of this plot.
+    ///
+    /// Perimeter is defined as the number of cell edges
+    /// that border a different plot, or the edge of the map.
+    fn perimeter(&self) -> usize {
+        self.0.perimeter()
+    }
+
+    /// Find the number of sides this plot has.
+    ///
+    /// A side is a straight stretch of fence, no matter
+    /// how many cell edges long it is.
+    ///
+    /// This was worked out along with the plot itself, so the
+    /// map it came from isn't actually needed any more.
+    pub fn sides(&self, _grid: &Grid<char>) -> usize {
+        self.0.sides()
+    }
+}
+
+/// File consists of a grid of characters.
+///
+/// I realize now this function is technically infalliable...
+/// or it was, until it started checking that the grid is rectangular.
+pub fn parse_input(file: &str) -> Result<Grid<char>, AdventError> {
+    parse::char_grid(file)
+}
+
+pub fn part_one(data: &Grid<char>) -> usize {
+    find_plots(data)
+        .iter()
+        .map(|plot| plot.area() * plot.perimeter())
+        .sum()
+}
+
+/// Find the total price of fencing with the bulk discount.
+///
+/// With the discount, each plot costs its area times
+/// its number of sides instead of its perimeter.
+pub fn part_two(data: &Grid<char>) -> usize {
+    find_plots(data)
+        .iter()
+        .map(|plot| plot.area() * plot.sides(data))
+        .sum()
+}
+
+/// Split the map up into its plots.
+fn find_plots(grid: &Grid<char>) -> Vec<Plot> {
+    components::label(grid).into_iter().map(Plot).collect()
+}
+
+/// The solution to day 12.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    const TITLE: &'static str = "Garden Groups";
+
+    type Input = Grid<char>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse_input(text: &str) -> Result<Self::Input, AdventError> {
+        parse_input(text)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne, AdventError> {
+        Ok(part_one(input))
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Option<Self::PartTwo>, AdventError> {
+        Ok(Some(part_two(input)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::assert_grid_eq;
+    use std::fs::read_to_string;
+
+    use std::sync::LazyLock;
+
+    static INPUT: LazyLock<Grid<char>> = LazyLock::new(|| {
+        let file = read_to_string("src/input/day12-test.txt").unwrap();
+
+        parse_input(&file).unwrap()
+    });
+
+    #[test]
+    fn test_parse_input() {
+        let grid = parse_input("AAB\nACC").unwrap();
+
+        assert_grid_eq!(grid, Grid::new(3, 2, vec!['A', 'A', 'B', 'A', 'C', 'C']));
+        assert_eq!(INPUT.get((9, 9)), Some(&'E'));
+    }
+
+    #[test]
+    fn test_part_one() {
+        let data = &*INPUT;
+
+        assert_eq!(part_one(data), 1930);
+    }
+
+    #[test]
+    fn test_sides() {
+        let grid = parse_input("AAAA\nBBCD\nBBCC\nEEEC").unwrap();
+        let sides = find_plots(&grid)
+            .iter()
+            .map(|plot| (*plot.0.symbol(), plot.sides(&grid)))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            sides,
+            vec![('A', 4), ('B', 4), ('C', 8), ('D', 4), ('E', 4)]
+        );
+    }
+
+    #[test]
+    fn test_part_two() {
+        let data = &*INPUT;
+
+        assert_eq!(part_two(data), 1206);
+    }
+
+    #[test]
+    fn test_part_two_small() {
+        let small = parse_input("AAAA\nBBCD\nBBCC\nEEEC").unwrap();
+        let letter_e = parse_input("EEEEE\nEXXXX\nEEEEE\nEXXXX\nEEEEE").unwrap();
+        let letters = parse_input("AAAAAA\nAAABBA\nAAABBA\nABBAAA\nABBAAA\nAAAAAA").unwrap();
+
+        assert_eq!(part_two(&small), 80);
+        assert_eq!(part_two(&letter_e), 236);
+        assert_eq!(part_two(&letters), 368);
+    }
+}
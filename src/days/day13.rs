@@ -0,0 +1,517 @@
+//! # Day 13: Claw Contraption
+//!
+//! We're playing with a claw machine! And this one
+//! isn't rigged like the usual ones! It's _worse!_
+
+use std::sync::LazyLock;
+
+use crate::{parse, AdventError, Solution};
+
+use regex::Regex;
+
+/// How much further away the prizes really are.
+const OFFSET: i64 = 10_000_000_000_000;
+
+static MACHINE_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"Button A: X\+(\d+), Y\+(\d+)\s*\nButton B: X\+(\d+), Y\+(\d+)\s*\nPrize: X=(\d+), Y=(\d+)",
+    )
+    .expect("Regex should be valid")
+});
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Machine {
+    alpha: (i64, i64),
+    bravo: (i64, i64),
+    prize: (i64, i64),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// The rules every claw machine plays by.
+pub struct ClawRules {
+    /// How many tokens pressing Button A costs.
+    pub cost_a: i64,
+    /// How many tokens pressing Button B costs.
+    pub cost_b: i64,
+    /// The most times either button can be pressed, if there's a limit.
+    pub max_presses: Option<i64>,
+    /// How much further away each prize is than the machine says.
+    pub offset: i64,
+}
+
+impl ClawRules {
+    /// The rules as they first seem to be.
+    pub const PART_ONE: ClawRules = ClawRules {
+        cost_a: 3,
+        cost_b: 1,
+        max_presses: Some(100),
+        offset: 0,
+    };
+
+    /// The rules once the unit conversion error is found.
+    pub const PART_TWO: ClawRules = ClawRules {
+        max_presses: None,
+        offset: OFFSET,
+        ..ClawRules::PART_ONE
+    };
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// How a claw machine's prize can be won.
+pub enum Presses {
+    /// There is exactly one way to reach the prize, pressing
+    /// Button A and then Button B this many times.
+    Unique(i64, i64),
+    /// The buttons move the claw along the same line as one another,
+    /// so there may be many ways to reach the prize, or just the one.
+    /// This is the cheapest of them, along with how many there are.
+    Collinear(i64, i64, Ways),
+    /// The prize can't be reached with whole, non-negative presses
+    /// within the limit. What a ripoff!
+    Impossible,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// How many ways there are to win a prize with collinear buttons.
+pub enum Ways {
+    /// Only this many.
+    Finite(u128),
+    /// A button that doesn't move the claw can be pressed
+    /// any number of times, when there's no limit.
+    Infinite,
+}
+
+impl Presses {
+    /// The number of tokens needed to win the prize this way.
+    pub fn tokens(&self, rules: &ClawRules) -> Option<i64> {
+        match *self {
+            Presses::Unique(a, b) | Presses::Collinear(a, b, _) => {
+                Some(a * rules.cost_a + b * rules.cost_b)
+            }
+            Presses::Impossible => None,
+        }
+    }
+}
+
+impl Machine {
+    /// Work out how to get the prize in this machine.
+    ///
+    /// The cost of each button decides which way is best
+    /// when there are many to choose from.
+    pub fn solve(&self, rules: &ClawRules) -> Presses {
+        let offset = rules.offset as i128;
+        let limit = rules.max_presses.map(|limit| limit as i128);
+        let alpha = (self.alpha.0 as i128, self.alpha.1 as i128);
+        let bravo = (self.bravo.0 as i128, self.bravo.1 as i128);
+        let prize = (self.prize.0 as i128 + offset, self.prize.1 as i128 + offset);
+        let costs = (rules.cost_a as i128, rules.cost_b as i128);
+
+        let determinant = cross(alpha, bravo);
+        if determinant != 0 {
+            // Cramer's rule, keeping only whole answers.
+            let a_presses = cross(prize, bravo);
+            let b_presses = cross(alpha, prize);
+            if a_presses % determinant != 0 || b_presses % determinant != 0 {
+                return Presses::Impossible;
+            }
+
+            let (a_presses, b_presses) = (a_presses / determinant, b_presses / determinant);
+            if limit.is_some_and(|limit| a_presses > limit || b_presses > limit) {
+                return Presses::Impossible;
+            }
+
+            return match presses(a_presses, b_presses) {
+                Some((a, b)) => Presses::Unique(a, b),
+                None => Presses::Impossible,
+            };
+        }
+
+        // The buttons are collinear, so the prize has to be on their line too.
+        if cross(alpha, prize) != 0 || cross(bravo, prize) != 0 {
+            return Presses::Impossible;
+        }
+
+        // Everything is on one line, so one axis is enough to go on,
+        // as long as the buttons actually move along it.
+        let (alpha, bravo, prize) = if alpha.0 != 0 || bravo.0 != 0 {
+            (alpha.0, bravo.0, prize.0)
+        } else {
+            (alpha.1, bravo.1, prize.1)
+        };
+
+        let Some((a, b, ways)) = cheapest_on_line(alpha, bravo, prize, costs, limit) else {
+            return Presses::Impossible;
+        };
+        match presses(a, b) {
+            Some((a, b)) => Presses::Collinear(a, b, ways),
+            None => Presses::Impossible,
+        }
+    }
+}
+
+/// The cross product of two vectors, which is zero when they are collinear.
+fn cross(one: (i128, i128), two: (i128, i128)) -> i128 {
+    one.0 * two.1 - one.1 * two.0
+}
+
+/// Turn press counts back into [i64]s, if they're valid presses at all.
+fn presses(a: i128, b: i128) -> Option<(i64, i64)> {
+    if a < 0 || b < 0 {
+        return None;
+    }
+
+    Some((a.try_into().ok()?, b.try_into().ok()?))
+}
+
+/// Find the cheapest whole, non-negative `a` and `b` where
+/// `a * alpha + b * bravo == prize`, and neither is above `limit`,
+/// along with how many such `a` and `b` there are.
+///
+/// The cost goes up or down steadily as presses of one button are
+/// traded for the other, so the cheapest is always at one end or
+/// the other of the presses that work.
+fn cheapest_on_line(
+    alpha: i128,
+    bravo: i128,
+    prize: i128,
+    costs: (i128, i128),
+    limit: Option<i128>,
+) -> Option<(i128, i128, Ways)> {
+    let within = |presses: i128| limit.is_none_or(|limit| presses <= limit);
+    // How many ways there are to press a button that doesn't move the claw.
+    let free = limit.map_or(Ways::Infinite, |limit| Ways::Finite(limit as u128 + 1));
+    match (alpha, bravo) {
+        (0, 0) => {
+            let ways = match free {
+                Ways::Finite(ways) => Ways::Finite(ways * ways),
+                Ways::Infinite => Ways::Infinite,
+            };
+            return (prize == 0).then_some((0, 0, ways));
+        }
+        // A button that doesn't move the claw is never worth pressing.
+        (0, _) => {
+            return (prize % bravo == 0 && within(prize / bravo)).then(|| (0, prize / bravo, free))
+        }
+        (_, 0) => {
+            return (prize % alpha == 0 && within(prize / alpha)).then(|| (prize / alpha, 0, free))
+        }
+        _ => {}
+    }
+
+    let (gcd, inverse, _) = extended_gcd(alpha, bravo);
+    if prize % gcd != 0 {
+        return None;
+    }
+    let (alpha, bravo, prize) = (alpha / gcd, bravo / gcd, prize / gcd);
+
+    // Pressing A too few times means pressing B too many.
+    let least_possible = match limit {
+        Some(limit) => div_ceil((prize - limit * bravo).max(0), alpha),
+        None => 0,
+    };
+    let most_possible = match limit {
+        Some(limit) => (prize / alpha).min(limit),
+        None => prize / alpha,
+    };
+
+    // Presses of A that work are all equal modulo `bravo`.
+    let first = (prize * inverse).rem_euclid(bravo);
+    let fewest = first + div_ceil((least_possible - first).max(0), bravo) * bravo;
+    if fewest > most_possible {
+        return None;
+    }
+    let steps = (most_possible - fewest) / bravo;
+    let most = fewest + steps * bravo;
+
+    let ways = Ways::Finite(steps as u128 + 1);
+    [fewest, most]
+        .into_iter()
+        .map(|a| (a, (prize - a * alpha) / bravo, ways))
+        .min_by_key(|&(a, b, _)| a * costs.0 + b * costs.1)
+}
+
+/// Divide a non-negative number by a positive one, rounding up.
+fn div_ceil(numerator: i128, denominator: i128) -> i128 {
+    (numerator + denominator - 1) / denominator
+}
+
+/// Find `gcd(a, b)` along with `x` and `y` where `a * x + b * y == gcd(a, b)`.
+fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        (a, 1, 0)
+    } else {
+        let (gcd, x, y) = extended_gcd(b, a % b);
+        (gcd, y, x - a / b * y)
+    }
+}
+
+pub fn parse_input(file: &str) -> Result<Vec<Machine>, AdventError> {
+    parse::sections(file)
+        .map(|machine| {
+            let [alpha_x, alpha_y, bravo_x, bravo_y, prize_x, prize_y] =
+                parse::captures(file, &MACHINE_RE, machine, "a claw machine description")?;
+
+            Ok(Machine {
+                alpha: (alpha_x, alpha_y),
+                bravo: (bravo_x, bravo_y),
+                prize: (prize_x, prize_y),
+            })
+        })
+        .collect()
+}
+
+/// Get the minimum number of tokens required to get the maximum number of possible prizes.
+pub fn part_one(data: &[Machine]) -> i64 {
+    tokens(data, &ClawRules::PART_ONE)
+}
+
+/// Get the minimum number of tokens required once the prizes turn
+/// out to be much, _much_ further away than they looked.
+pub fn part_two(data: &[Machine]) -> i64 {
+    tokens(data, &ClawRules::PART_TWO)
+}
+
+/// Get the minimum number of tokens required to win every
+/// prize that can be won, playing by any set of rules.
+pub fn tokens(data: &[Machine], rules: &ClawRules) -> i64 {
+    data.iter()
+        .filter_map(|machine| machine.solve(rules).tokens(rules))
+        .sum()
+}
+
+/// The solution to day 13.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    const TITLE: &'static str = "Claw Contraption";
+
+    type Input = Vec<Machine>;
+    type PartOne = i64;
+    type PartTwo = i64;
+
+    fn parse_input(text: &str) -> Result<Self::Input, AdventError> {
+        parse_input(text)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne, AdventError> {
+        Ok(part_one(input))
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Option<Self::PartTwo>, AdventError> {
+        Ok(Some(part_two(input)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::fs::read_to_string;
+
+    use std::sync::LazyLock;
+
+    static INPUT: LazyLock<Vec<Machine>> = LazyLock::new(|| {
+        let file = read_to_string("src/input/day13-test.txt").unwrap();
+
+        parse_input(&file).unwrap()
+    });
+
+    #[test]
+    fn test_parse_input() {
+        let data = &*INPUT;
+
+        assert_eq!(
+            data[0],
+            Machine {
+                alpha: (94, 34),
+                bravo: (22, 67),
+                prize: (8400, 5400),
+            }
+        );
+
+        // Windows line endings shouldn't get in the way.
+        let file = read_to_string("src/input/day13-test.txt").unwrap();
+        let crlf = parse_input(&file.replace('\n', "\r\n")).unwrap();
+        assert_eq!(&crlf, data);
+    }
+
+    #[test]
+    fn test_solve() {
+        let data = &*INPUT;
+
+        assert_eq!(data[0].solve(&ClawRules::PART_ONE), Presses::Unique(80, 40));
+        assert_eq!(data[1].solve(&ClawRules::PART_ONE), Presses::Impossible);
+        assert_eq!(data[2].solve(&ClawRules::PART_ONE), Presses::Unique(38, 86));
+        assert_eq!(data[3].solve(&ClawRules::PART_ONE), Presses::Impossible);
+
+        assert_eq!(data[0].solve(&ClawRules::PART_TWO), Presses::Impossible);
+        assert!(matches!(
+            data[1].solve(&ClawRules::PART_TWO),
+            Presses::Unique(..)
+        ));
+    }
+
+    #[test]
+    fn test_solve_negative() {
+        // The only way to get there would be to un-press Button B.
+        let machine = Machine {
+            alpha: (1, 1),
+            bravo: (2, 1),
+            prize: (1, 2),
+        };
+
+        assert_eq!(machine.solve(&ClawRules::PART_ONE), Presses::Impossible);
+    }
+
+    /// Both buttons cost the same, and there's no limit.
+    const EVEN: ClawRules = ClawRules {
+        cost_a: 1,
+        cost_b: 1,
+        max_presses: None,
+        offset: 0,
+    };
+
+    /// The usual costs, but there's no limit.
+    const PRICEY_A: ClawRules = ClawRules { cost_a: 3, ..EVEN };
+
+    #[test]
+    fn test_solve_collinear() {
+        let machine = Machine {
+            alpha: (2, 2),
+            bravo: (1, 1),
+            prize: (10, 10),
+        };
+
+        // 10 = 2a + b works for a from 0 up to 5.
+        assert_eq!(
+            machine.solve(&PRICEY_A),
+            Presses::Collinear(0, 10, Ways::Finite(6))
+        );
+        assert_eq!(
+            machine.solve(&EVEN),
+            Presses::Collinear(5, 0, Ways::Finite(6))
+        );
+        assert_eq!(
+            machine.solve(&ClawRules { offset: 1, ..EVEN }),
+            Presses::Collinear(5, 1, Ways::Finite(6))
+        );
+
+        let off_line = Machine {
+            prize: (10, 11),
+            ..machine
+        };
+        assert_eq!(off_line.solve(&ClawRules::PART_ONE), Presses::Impossible);
+
+        let too_coarse = Machine {
+            alpha: (4, 6),
+            bravo: (6, 9),
+            prize: (11, 33),
+        };
+        assert_eq!(too_coarse.solve(&ClawRules::PART_ONE), Presses::Impossible);
+
+        let mixed = Machine {
+            alpha: (6, 3),
+            bravo: (4, 2),
+            prize: (22, 11),
+        };
+        // 22 = 6a + 4b works for (3, 1) and (1, 4).
+        assert_eq!(
+            mixed.solve(&EVEN),
+            Presses::Collinear(3, 1, Ways::Finite(2))
+        );
+        assert_eq!(
+            mixed.solve(&PRICEY_A),
+            Presses::Collinear(1, 4, Ways::Finite(2))
+        );
+
+        let just_one = Machine {
+            alpha: (2, 2),
+            bravo: (3, 3),
+            prize: (5, 5),
+        };
+        // 5 = 2a + 3b only works for (1, 1) without pressing a button
+        // a negative number of times, like (4, -1).
+        assert_eq!(
+            just_one.solve(&EVEN),
+            Presses::Collinear(1, 1, Ways::Finite(1))
+        );
+        assert_eq!(
+            just_one.solve(&PRICEY_A),
+            Presses::Collinear(1, 1, Ways::Finite(1))
+        );
+
+        // Button A does nothing, so it can be pressed any number of times.
+        let idle_a = Machine {
+            alpha: (0, 0),
+            bravo: (2, 3),
+            prize: (4, 6),
+        };
+        assert_eq!(
+            idle_a.solve(&EVEN),
+            Presses::Collinear(0, 2, Ways::Infinite)
+        );
+        assert_eq!(
+            idle_a.solve(&ClawRules::PART_ONE),
+            Presses::Collinear(0, 2, Ways::Finite(101))
+        );
+
+        let idle = Machine {
+            alpha: (0, 0),
+            bravo: (0, 0),
+            prize: (0, 0),
+        };
+        assert_eq!(idle.solve(&EVEN), Presses::Collinear(0, 0, Ways::Infinite));
+        assert_eq!(
+            idle.solve(&ClawRules::PART_ONE),
+            Presses::Collinear(0, 0, Ways::Finite(101 * 101))
+        );
+    }
+
+    #[test]
+    fn test_solve_limited() {
+        let data = &*INPUT;
+        let strict = ClawRules {
+            max_presses: Some(80),
+            ..ClawRules::PART_ONE
+        };
+
+        assert_eq!(data[0].solve(&strict), Presses::Unique(80, 40));
+        assert_eq!(data[2].solve(&strict), Presses::Impossible);
+        assert_eq!(tokens(data, &strict), 280);
+
+        // 22 = 6a + 4b works for (3, 1) and (1, 4), but only one fits.
+        let mixed = Machine {
+            alpha: (6, 3),
+            bravo: (4, 2),
+            prize: (22, 11),
+        };
+        let limited = ClawRules {
+            max_presses: Some(3),
+            ..PRICEY_A
+        };
+        assert_eq!(
+            mixed.solve(&limited),
+            Presses::Collinear(3, 1, Ways::Finite(1))
+        );
+        let limited = ClawRules {
+            max_presses: Some(2),
+            ..PRICEY_A
+        };
+        assert_eq!(mixed.solve(&limited), Presses::Impossible);
+    }
+
+    #[test]
+    fn test_part_one() {
+        let data = &*INPUT;
+
+        assert_eq!(part_one(data), 480);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let data = &*INPUT;
+
+        assert_eq!(part_two(data), 875318608908);
+    }
+}
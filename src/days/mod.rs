@@ -0,0 +1,224 @@
+//! The solutions to each day's puzzle.
+
+use std::any::TypeId;
+use std::convert::Infallible;
+
+use crate::input::normalize;
+use crate::timing::{self, Timings};
+use crate::{AdventError, Part, Solution};
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+
+/// Every day that has a solution, in order.
+pub static DAYS: [Day; 13] = [
+    Day::of::<day01::Day01>(),
+    Day::of::<day02::Day02>(),
+    Day::of::<day03::Day03>(),
+    Day::of::<day04::Day04>(),
+    Day::of::<day05::Day05>(),
+    Day::of::<day06::Day06>(),
+    Day::of::<day07::Day07>(),
+    Day::of::<day08::Day08>(),
+    Day::of::<day09::Day09>(),
+    Day::of::<day10::Day10>(),
+    Day::of::<day11::Day11>(),
+    Day::of::<day12::Day12>(),
+    Day::of::<day13::Day13>(),
+];
+
+/// The answer to each part that was asked for, or [Option::None]
+/// if that part hasn't been solved yet.
+pub type Solved = Vec<(Part, Option<String>)>;
+
+/// Find the registered solution for `day`, if there is one.
+pub fn find(day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|entry| entry.number == day)
+}
+
+#[derive(Clone, Copy, Debug)]
+/// A registered [Solution], with the types erased so that
+/// every day can be handled the same way.
+pub struct Day {
+    /// The day of the month the puzzle was released.
+    pub number: u8,
+    /// The title of the puzzle.
+    pub title: &'static str,
+    solve: fn(&str, Option<Part>) -> Result<Solved, AdventError>,
+    time: fn(&str, usize) -> Result<Timings, AdventError>,
+    solved: fn(Part) -> bool,
+}
+
+impl Day {
+    /// Register the solution `S`.
+    pub const fn of<S: Solution + 'static>() -> Day {
+        Day {
+            number: S::DAY,
+            title: S::TITLE,
+            solve: solve::<S>,
+            time: timing::time::<S>,
+            solved: is_solved::<S>,
+        }
+    }
+
+    /// Run this day's solution against the puzzle input in `file`.
+    ///
+    /// `file` is [normalize]d first, so it doesn't matter which
+    /// line endings it uses.
+    ///
+    /// This gives the answer to each part selected by `part`, with
+    /// [Option::None] selecting both. Parts that haven't been solved
+    /// yet have no answer.
+    pub fn solve(&self, file: &str, part: Option<Part>) -> Result<Solved, AdventError> {
+        (self.solve)(&normalize(file), part)
+    }
+
+    /// Time each step of this day's solution against the puzzle
+    /// input in `file`, over `iterations` runs.
+    ///
+    /// Like [Day::solve], `file` is [normalize]d first.
+    pub fn time(&self, file: &str, iterations: usize) -> Result<Timings, AdventError> {
+        (self.time)(&normalize(file), iterations)
+    }
+
+    /// Check whether `part` of this day has been solved.
+    pub fn is_solved(&self, part: Part) -> bool {
+        (self.solved)(part)
+    }
+
+    /// Count the number of stars this day has earned.
+    pub fn stars(&self) -> usize {
+        [Part::One, Part::Two]
+            .into_iter()
+            .filter(|&part| self.is_solved(part))
+            .count()
+    }
+}
+
+/// Solve the puzzle `S` for `file`, giving the answers to `part`.
+fn solve<S: Solution>(file: &str, part: Option<Part>) -> Result<Solved, AdventError> {
+    let input = S::parse_input(file)?;
+    let mut answers = Vec::new();
+
+    if Part::One.is_selected(part) {
+        answers.push((Part::One, Some(S::part_one(&input)?.to_string())));
+    }
+    if Part::Two.is_selected(part) {
+        let answer = S::part_two(&input)?.map(|answer| answer.to_string());
+        answers.push((Part::Two, answer));
+    }
+    Ok(answers)
+}
+
+/// Check whether `part` of the puzzle `S` has been solved.
+///
+/// Part one always has been, since there's no getting to part two
+/// without it. Part two counts as long as its answer isn't
+/// [Infallible], which is what [Solution] asks unsolved days to use.
+fn is_solved<S: Solution + 'static>(part: Part) -> bool {
+    match part {
+        Part::One => true,
+        Part::Two => TypeId::of::<S::PartTwo>() != TypeId::of::<Infallible>(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// A day with only the first part solved.
+    struct HalfDone;
+
+    impl Solution for HalfDone {
+        const DAY: u8 = 25;
+        const TITLE: &'static str = "Half Done";
+
+        type Input = usize;
+        type PartOne = usize;
+        type PartTwo = Infallible;
+
+        fn parse_input(text: &str) -> Result<Self::Input, AdventError> {
+            Ok(text.len())
+        }
+
+        fn part_one(input: &Self::Input) -> Result<Self::PartOne, AdventError> {
+            Ok(*input)
+        }
+    }
+
+    #[test]
+    fn test_registry_order() {
+        for (idx, day) in DAYS.iter().enumerate() {
+            assert_eq!(
+                day.number as usize,
+                idx + 1,
+                "Day {} out of order",
+                day.title
+            );
+        }
+    }
+
+    #[test]
+    fn test_solve() {
+        let day = find(1).unwrap();
+        let file = std::fs::read_to_string("src/input/day01-test.txt").unwrap();
+
+        assert_eq!(
+            day.solve(&file, None).unwrap(),
+            vec![
+                (Part::One, Some("11".to_string())),
+                (Part::Two, Some("31".to_string()))
+            ]
+        );
+        assert_eq!(
+            Day::of::<HalfDone>()
+                .solve("12345", Some(Part::Two))
+                .unwrap(),
+            vec![(Part::Two, None)]
+        );
+    }
+
+    /// Every sample should solve the same whether or not it
+    /// has been through a Windows text editor first.
+    #[test]
+    fn test_crlf_samples() {
+        let samples = std::fs::read_dir("src/input")
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+            .filter(|name| name.contains("-test"))
+            .collect::<Vec<_>>();
+        assert!(!samples.is_empty());
+
+        for name in samples {
+            let day = name[3..5].parse::<u8>().unwrap();
+            let day = find(day).unwrap();
+            let file = std::fs::read_to_string(format!("src/input/{}", name)).unwrap();
+            let crlf = format!("\u{feff}{}\r\n\r\n", file.replace('\n', " \r\n"));
+
+            assert_eq!(
+                day.solve(&crlf, None).unwrap(),
+                day.solve(&file, None).unwrap(),
+                "{} solves differently with CRLF line endings",
+                name
+            );
+        }
+    }
+
+    #[test]
+    fn test_stars() {
+        assert_eq!(find(1).map(Day::stars), Some(2));
+        assert_eq!(Day::of::<HalfDone>().stars(), 1);
+        assert!(find(25).is_none());
+    }
+}
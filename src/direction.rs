@@ -0,0 +1,161 @@
+//! Directions to move around a grid in.
+//!
+//! Grids are indexed by column and row, with row 0 at the top,
+//! so north is towards row 0 and east is towards higher columns.
+
+/// Anything that describes a single step across a grid.
+pub trait Delta {
+    /// The change in `(column, row)` from taking one step this way.
+    fn delta(self) -> (isize, isize);
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// One of the four cardinal directions.
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    /// Every direction, clockwise from north.
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    /// The direction a quarter turn clockwise from this one.
+    pub fn turn_right(self) -> Direction {
+        match self {
+            Direction::North => Direction::East,
+            Direction::East => Direction::South,
+            Direction::South => Direction::West,
+            Direction::West => Direction::North,
+        }
+    }
+
+    /// The direction a quarter turn counterclockwise from this one.
+    pub fn turn_left(self) -> Direction {
+        self.opposite().turn_right()
+    }
+
+    /// The direction facing the other way.
+    pub fn opposite(self) -> Direction {
+        self.turn_right().turn_right()
+    }
+}
+
+impl Delta for Direction {
+    fn delta(self) -> (isize, isize) {
+        match self {
+            Direction::North => (0, -1),
+            Direction::East => (1, 0),
+            Direction::South => (0, 1),
+            Direction::West => (-1, 0),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// One of the eight points of the compass, diagonals included.
+pub enum Compass8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Compass8 {
+    /// Every point of the compass, clockwise from north.
+    pub const ALL: [Compass8; 8] = [
+        Compass8::North,
+        Compass8::NorthEast,
+        Compass8::East,
+        Compass8::SouthEast,
+        Compass8::South,
+        Compass8::SouthWest,
+        Compass8::West,
+        Compass8::NorthWest,
+    ];
+
+    /// The point an eighth of a turn clockwise from this one.
+    pub fn turn_right(self) -> Compass8 {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    /// The point an eighth of a turn counterclockwise from this one.
+    pub fn turn_left(self) -> Compass8 {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    /// The point facing the other way.
+    pub fn opposite(self) -> Compass8 {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+}
+
+impl Delta for Compass8 {
+    fn delta(self) -> (isize, isize) {
+        match self {
+            Compass8::North => (0, -1),
+            Compass8::NorthEast => (1, -1),
+            Compass8::East => (1, 0),
+            Compass8::SouthEast => (1, 1),
+            Compass8::South => (0, 1),
+            Compass8::SouthWest => (-1, 1),
+            Compass8::West => (-1, 0),
+            Compass8::NorthWest => (-1, -1),
+        }
+    }
+}
+
+impl From<Direction> for Compass8 {
+    fn from(dir: Direction) -> Self {
+        match dir {
+            Direction::North => Compass8::North,
+            Direction::East => Compass8::East,
+            Direction::South => Compass8::South,
+            Direction::West => Compass8::West,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_turns() {
+        assert_eq!(Direction::North.turn_right(), Direction::East);
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+        assert_eq!(Direction::East.opposite(), Direction::West);
+
+        assert_eq!(Compass8::North.turn_right(), Compass8::NorthEast);
+        assert_eq!(Compass8::North.turn_left(), Compass8::NorthWest);
+        assert_eq!(Compass8::SouthWest.opposite(), Compass8::NorthEast);
+        assert_eq!(Compass8::NorthWest.turn_right(), Compass8::North);
+
+        // Turns index into `ALL` by discriminant.
+        for (idx, dir) in Compass8::ALL.into_iter().enumerate() {
+            assert_eq!(dir as usize, idx);
+        }
+    }
+
+    #[test]
+    fn test_deltas_agree() {
+        for dir in Direction::ALL {
+            assert_eq!(dir.delta(), Compass8::from(dir).delta());
+        }
+        for dir in Compass8::ALL {
+            let (x, y) = dir.delta();
+            assert_eq!(dir.opposite().delta(), (-x, -y));
+        }
+    }
+}
@@ -0,0 +1,205 @@
+//! Errors, and pointing at where in the input they came from.
+
+use std::fmt;
+use std::io::Error as IOError;
+use std::num::ParseIntError;
+
+use thiserror::Error;
+
+use crate::input::InputSource;
+
+#[derive(Error, Debug)]
+pub enum AdventError {
+    #[error("Error reading {input}: {error}")]
+    /// An error reading the file used as input.
+    File {
+        /// Where we tried to read the input from.
+        input: InputSource,
+        #[source]
+        error: IOError,
+    },
+    #[error("Error writing {output}: {error}")]
+    /// An error writing output, like an animation's frames.
+    Write {
+        /// Where we tried to write to.
+        output: String,
+        #[source]
+        error: IOError,
+    },
+    /// An error in the format of the input file.
+    #[error("Parsing error: {0}")]
+    Parse(String),
+    /// The input didn't have the shape we expected at a specific spot.
+    #[error("Parsing error at {location}: expected {expected}, found {:?}", location.snippet)]
+    Syntax {
+        location: Location,
+        /// A description of what should have been there instead.
+        expected: String,
+    },
+    /// A number in the input couldn't be parsed.
+    #[error("Parsing error at {location}: invalid number {:?}", location.snippet)]
+    Number {
+        location: Location,
+        #[source]
+        source: ParseIntError,
+    },
+    /// The input has violated a contract.
+    #[error("Contract violation: {0}")]
+    Data(String),
+}
+
+impl AdventError {
+    /// Complain that `snippet`, a slice of `text`, isn't what was `expected`.
+    pub fn syntax(text: &str, snippet: &str, expected: impl Into<String>) -> AdventError {
+        AdventError::Syntax {
+            location: Location::of(text, snippet),
+            expected: expected.into(),
+        }
+    }
+
+    /// Complain that `snippet`, a slice of `text`, isn't a valid number.
+    pub fn number(text: &str, snippet: &str, source: ParseIntError) -> AdventError {
+        AdventError::Number {
+            location: Location::of(text, snippet),
+            source,
+        }
+    }
+
+    /// Get where in the input this error happened, if we know.
+    pub fn location(&self) -> Option<&Location> {
+        match self {
+            AdventError::Syntax { location, .. } | AdventError::Number { location, .. } => {
+                Some(location)
+            }
+            _ => None,
+        }
+    }
+
+    /// Render this error as a diagnostic, pointing at the offending
+    /// part of `text` with carets.
+    ///
+    /// `text` should be the input that caused this error. Errors
+    /// without a location are rendered as just their message.
+    ///
+    /// ```notrust
+    /// Parsing error at line 2, column 3: invalid number "x3"
+    ///   |
+    /// 2 | 4 x3
+    ///   |   ^^
+    /// ```
+    pub fn render(&self, text: &str) -> String {
+        let Some(location) = self.location() else {
+            return self.to_string();
+        };
+        let Some(line) = text.lines().nth(location.line - 1) else {
+            return self.to_string();
+        };
+
+        let gutter = location.line.to_string().len();
+        let carets = location.snippet.chars().count().max(1);
+        format!(
+            "{message}\n{blank:gutter$} |\n{number} | {line}\n{blank:gutter$} | {blank:indent$}{carets}",
+            message = self,
+            blank = "",
+            number = location.line,
+            indent = location.column - 1,
+            carets = "^".repeat(carets),
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+/// A spot in the puzzle input.
+pub struct Location {
+    /// The line number, starting from 1.
+    pub line: usize,
+    /// The column, in characters, starting from 1.
+    pub column: usize,
+    /// The offending text itself.
+    pub snippet: String,
+}
+
+impl Location {
+    /// Find where `snippet` is within `text`.
+    ///
+    /// `snippet` should be a slice of `text`, as you get from
+    /// splitting it up while parsing. Otherwise the location is
+    /// just reported as the start of `text`.
+    pub fn of(text: &str, snippet: &str) -> Location {
+        let start = text.as_ptr() as usize;
+        let offset = (snippet.as_ptr() as usize)
+            .checked_sub(start)
+            .filter(|&offset| offset + snippet.len() <= text.len())
+            .unwrap_or(0);
+
+        let before = &text[..offset];
+        let line_start = before.rfind('\n').map_or(0, |idx| idx + 1);
+
+        Location {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            snippet: snippet.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use std::error::Error;
+
+    static TEXT: &str = "3   4\n4 x3\n2   5";
+
+    #[test]
+    fn test_location() {
+        let snippet = &TEXT[8..10];
+        assert_eq!(snippet, "x3");
+
+        assert_eq!(
+            Location::of(TEXT, snippet),
+            Location {
+                line: 2,
+                column: 3,
+                snippet: "x3".to_string()
+            }
+        );
+        assert_eq!(Location::of(TEXT, &TEXT[..1]).line, 1);
+        assert_eq!(Location::of(TEXT, &TEXT[12..]).line, 3);
+    }
+
+    #[test]
+    fn test_number_source() {
+        let snippet = &TEXT[8..10];
+        let err = snippet.parse::<u32>().unwrap_err();
+        let err = AdventError::number(TEXT, snippet, err);
+
+        assert!(err.source().is_some());
+        assert_eq!(
+            err.to_string(),
+            "Parsing error at line 2, column 3: invalid number \"x3\""
+        );
+    }
+
+    #[test]
+    fn test_render() {
+        let err = AdventError::syntax(TEXT, &TEXT[8..10], "a number");
+
+        assert_eq!(
+            err.render(TEXT),
+            "Parsing error at line 2, column 3: expected a number, found \"x3\"
+  |
+2 | 4 x3
+  |   ^^"
+        );
+
+        let err = AdventError::Parse("Expected two-part data format".to_string());
+        assert_eq!(err.render(TEXT), err.to_string());
+    }
+}
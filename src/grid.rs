@@ -0,0 +1,306 @@
+//! Extra tools for working with [Grid]s.
+
+use std::fmt;
+
+use simple_grid::{Grid, GridIndex};
+
+use crate::direction::Delta;
+use crate::point::Point;
+
+/// Extensions to [Grid] for moving around it.
+pub trait GridExt {
+    /// The type of each cell.
+    type Cell;
+
+    /// Take one step from `idx` in the direction `dir`.
+    ///
+    /// This gives [Option::None] if the step would leave the grid.
+    fn step(&self, idx: GridIndex, dir: impl Delta) -> Option<GridIndex>;
+
+    /// The grid index at `point`, if it is on the grid.
+    fn index_of(&self, point: Point) -> Option<GridIndex>;
+
+    /// The cell at `point`, if it is on the grid.
+    fn at(&self, point: Point) -> Option<&Self::Cell>;
+
+    /// Draw the grid, using `render` to pick what to draw for each
+    /// cell, like a character or a number.
+    ///
+    /// Rows are separated by newlines, without one at the end.
+    fn display<F, R>(&self, render: F) -> GridDisplay<'_, Self::Cell, F>
+    where
+        F: Fn(&Self::Cell) -> R,
+        R: fmt::Display;
+}
+
+impl<T> GridExt for Grid<T> {
+    type Cell = T;
+
+    fn step(&self, idx: GridIndex, dir: impl Delta) -> Option<GridIndex> {
+        let (column, row) = dir.delta();
+        let column = idx.column().checked_add_signed(column)?;
+        let row = idx.row().checked_add_signed(row)?;
+
+        let next = GridIndex::new(column, row);
+        self.contains_index(next).then_some(next)
+    }
+
+    fn index_of(&self, point: Point) -> Option<GridIndex> {
+        point.to_index().filter(|&idx| self.contains_index(idx))
+    }
+
+    fn at(&self, point: Point) -> Option<&T> {
+        self.get(self.index_of(point)?)
+    }
+
+    fn display<F, R>(&self, render: F) -> GridDisplay<'_, T, F>
+    where
+        F: Fn(&T) -> R,
+        R: fmt::Display,
+    {
+        GridDisplay { grid: self, render }
+    }
+}
+
+/// A grid drawn as text, made by [GridExt::display].
+pub struct GridDisplay<'a, T, F> {
+    grid: &'a Grid<T>,
+    render: F,
+}
+
+impl<T, F, R> fmt::Display for GridDisplay<'_, T, F>
+where
+    F: Fn(&T) -> R,
+    R: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.grid.rows() {
+            if row > 0 {
+                writeln!(f)?;
+            }
+            for cell in self.grid.row_iter(row) {
+                write!(f, "{}", (self.render)(cell))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Compare two grids, describing where they differ if they do.
+///
+/// The description shows both grids side by side, drawn with `render`,
+/// along with a third grid marking each mismatched cell with an `X`.
+/// Cells only one of the grids has count as mismatched. Every cell is
+/// padded to the width of the widest, so the columns line up.
+pub fn diff<T, F, R>(left: &Grid<T>, right: &Grid<T>, render: F) -> Option<String>
+where
+    T: PartialEq,
+    F: Fn(&T) -> R,
+    R: fmt::Display,
+{
+    let width = left.width().max(right.width());
+    let height = left.height().max(right.height());
+
+    let mut mismatched = 0;
+    let marks = (0..height)
+        .map(|row| {
+            (0..width)
+                .map(|column| {
+                    let idx = GridIndex::new(column, row);
+                    if left.get(idx) == right.get(idx) {
+                        "."
+                    } else {
+                        mismatched += 1;
+                        "X"
+                    }
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    if mismatched == 0 {
+        return None;
+    }
+
+    let cells = |grid: &Grid<T>| {
+        grid.rows()
+            .map(|row| {
+                grid.row_iter(row)
+                    .map(|cell| render(cell).to_string())
+                    .collect()
+            })
+            .collect::<Vec<Vec<_>>>()
+    };
+    let (left_cells, right_cells) = (cells(left), cells(right));
+    let cell_width = left_cells
+        .iter()
+        .chain(&right_cells)
+        .flatten()
+        .map(|cell| cell.chars().count())
+        .max()
+        .unwrap_or(1);
+    let draw = |row: Option<&Vec<String>>| {
+        row.map_or(String::new(), |row| {
+            row.iter()
+                .map(|cell| format!("{:>cell_width$}", cell))
+                .collect()
+        })
+    };
+
+    let column = (width * cell_width).max("right".len());
+    let differ = if mismatched == 1 {
+        "cell differs"
+    } else {
+        "cells differ"
+    };
+    let mut message = format!(
+        "{} {} between the {}x{} left grid and the {}x{} right grid\n",
+        mismatched,
+        differ,
+        left.width(),
+        left.height(),
+        right.width(),
+        right.height(),
+    );
+    message += &format!("{:column$}  {:column$}  diff", "left", "right");
+    for (row, marks) in marks.iter().enumerate() {
+        let marks = marks
+            .iter()
+            .map(|mark| format!("{:>cell_width$}", mark))
+            .collect::<String>();
+        message += &format!(
+            "\n{:column$}  {:column$}  {}",
+            draw(left_cells.get(row)),
+            draw(right_cells.get(row)),
+            marks
+        );
+    }
+
+    Some(message)
+}
+
+/// Assert that two [Grid]s are equal, showing them side by side if not.
+///
+/// Cells are drawn with their [Display](std::fmt::Display) implementation
+/// by default, which suits grids of numbers or characters. Anything else
+/// needs a function from a cell to something displayable, like a
+/// character, as a third argument.
+///
+/// On failure, the grids are shown side by side as they are, and the
+/// mismatched cells are marked in a third grid beside them rather
+/// than in place, so that both grids can still be read exactly.
+///
+/// ```
+/// # use advent2024::assert_grid_eq;
+/// # use simple_grid::Grid;
+/// let grid = Grid::new(2, 1, vec![true, false]);
+///
+/// assert_grid_eq!(grid, Grid::new(2, 1, vec![true, false]), |&cell| {
+///     if cell { '#' } else { '.' }
+/// });
+/// ```
+#[macro_export]
+macro_rules! assert_grid_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::assert_grid_eq!($left, $right, |cell| ::std::string::ToString::to_string(
+            cell
+        ))
+    };
+    ($left:expr, $right:expr, $render:expr $(,)?) => {
+        if let Some(diff) = $crate::grid::diff(&$left, &$right, $render) {
+            panic!("assertion `left == right` failed: {}", diff);
+        }
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::direction::{Compass8, Direction};
+
+    #[test]
+    fn test_step() {
+        let grid = Grid::new(3, 2, vec![0; 6]);
+        let corner = GridIndex::new(0, 0);
+
+        assert_eq!(grid.step(corner, Direction::North), None);
+        assert_eq!(grid.step(corner, Direction::West), None);
+        assert_eq!(
+            grid.step(corner, Direction::East),
+            Some(GridIndex::new(1, 0))
+        );
+        assert_eq!(
+            grid.step(corner, Compass8::SouthEast),
+            Some(GridIndex::new(1, 1))
+        );
+        assert_eq!(grid.step(GridIndex::new(2, 1), Direction::East), None);
+        assert_eq!(grid.step(GridIndex::new(2, 1), Direction::South), None);
+    }
+
+    #[test]
+    fn test_display() {
+        let grid = Grid::new(3, 2, vec![true, false, false, false, true, true]);
+
+        let picture = grid.display(|&cell| if cell { '#' } else { '.' });
+        assert_eq!(picture.to_string(), "#..\n.##");
+
+        let grid = Grid::new(2, 2, vec![1, 23, 456, 7]);
+        assert_eq!(grid.display(|cell| cell * 10).to_string(), "10230\n456070");
+    }
+
+    #[test]
+    fn test_diff() {
+        let left = Grid::new(3, 2, vec![1, 2, 3, 4, 5, 6]);
+        let right = Grid::new(3, 2, vec![1, 2, 3, 4, 0, 6]);
+        let digit = |cell: &u32| char::from_digit(*cell, 10).unwrap();
+
+        assert_eq!(diff(&left, &left, digit), None);
+        assert_eq!(
+            diff(&left, &right, digit).unwrap(),
+            "\
+1 cell differs between the 3x2 left grid and the 3x2 right grid
+left   right  diff
+123    123    ...
+456    406    .X."
+        );
+
+        // Cells only in the bigger grid count too.
+        let wider = Grid::new(4, 2, vec![1, 2, 3, 0, 4, 5, 6, 0]);
+        assert!(diff(&left, &wider, digit)
+            .unwrap()
+            .starts_with("2 cells differ"));
+
+        // Wider cells are padded so the columns still line up.
+        let left = Grid::new(2, 2, vec![1, 2, 10, 3]);
+        let right = Grid::new(2, 2, vec![1, 2, 11, 3]);
+        assert_eq!(
+            diff(&left, &right, |cell| *cell).unwrap(),
+            "\
+1 cell differs between the 2x2 left grid and the 2x2 right grid
+left   right  diff
+ 1 2    1 2    . .
+10 3   11 3    X ."
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "left grid")]
+    fn test_assert_grid_eq() {
+        let grid = Grid::new(2, 2, vec!['a', 'b', 'c', 'd']);
+
+        assert_grid_eq!(grid, Grid::new(2, 2, vec!['a', 'b', 'c', 'd']));
+        assert_grid_eq!(grid, Grid::new(2, 2, vec!['a', 'b', 'c', 'e']));
+    }
+
+    #[test]
+    fn test_at() {
+        let grid = Grid::new(3, 2, (0..6).collect());
+
+        assert_eq!(grid.at(Point::new(2, 1)), Some(&5));
+        assert_eq!(grid.at(Point::new(-1, 0)), None);
+        assert_eq!(grid.at(Point::new(3, 0)), None);
+        assert_eq!(grid.index_of(Point::new(0, 1)), Some(GridIndex::new(0, 1)));
+    }
+}
@@ -0,0 +1,160 @@
+//! Finding and loading puzzle input.
+//!
+//! Input can come from an explicit path, from standard input, or
+//! from a directory of `dayXX.txt` files. That directory is either
+//! named by the `ADVENT_INPUT_DIR` environment variable, or it's
+//! the `src/input` directory used since day one.
+//!
+//! However it's found, the input is [normalize]d as it's read, so the
+//! days only ever have to deal with plain `\n`-separated lines.
+
+use std::env;
+use std::fmt;
+use std::fs::read_to_string;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+use crate::AdventError;
+
+/// The environment variable naming the directory puzzle inputs live in.
+pub const INPUT_DIR_VAR: &str = "ADVENT_INPUT_DIR";
+
+/// The directory puzzle inputs live in if nothing else is specified.
+pub const DEFAULT_INPUT_DIR: &str = "src/input";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+/// Where a puzzle's input should be read from.
+pub enum InputSource {
+    /// Read the input from standard input.
+    Stdin,
+    /// Read the input from a file.
+    Path(PathBuf),
+}
+
+impl InputSource {
+    /// Figure out where the input for `day` lives.
+    ///
+    /// An explicit `input` path always wins, with `-` meaning
+    /// standard input. Otherwise the input is `dayXX.txt` inside
+    /// the directory named by [INPUT_DIR_VAR], falling back
+    /// to [DEFAULT_INPUT_DIR].
+    pub fn resolve(day: u8, input: Option<&str>) -> InputSource {
+        Self::locate(day, input, Some(&input_dir()))
+    }
+
+    /// Like [InputSource::resolve], but with the input directory given
+    /// directly instead of read from the environment.
+    pub fn locate(day: u8, input: Option<&str>, dir: Option<&Path>) -> InputSource {
+        match input {
+            Some("-") => InputSource::Stdin,
+            Some(path) => InputSource::Path(PathBuf::from(path)),
+            None => {
+                let dir = dir.unwrap_or(Path::new(DEFAULT_INPUT_DIR));
+
+                InputSource::Path(dir.join(format!("day{:02}.txt", day)))
+            }
+        }
+    }
+
+    /// Read the entire input, [normalize]d.
+    pub fn read(&self) -> Result<String, AdventError> {
+        let text = match self {
+            InputSource::Stdin => {
+                let mut text = String::new();
+                io::stdin().read_to_string(&mut text).map(|_| text)
+            }
+            InputSource::Path(path) => read_to_string(path),
+        };
+
+        text.map(|text| normalize(&text))
+            .map_err(|error| AdventError::File {
+                input: self.clone(),
+                error,
+            })
+    }
+}
+
+/// Tidy up text that may have been through other editors or systems.
+///
+/// This removes a leading byte order mark, turns `\r\n` line endings
+/// into `\n`, and strips whitespace from the end of every line and
+/// blank lines from the end of the text. Anything left ends with
+/// exactly one `\n`.
+pub fn normalize(text: &str) -> String {
+    let text = text.strip_prefix('\u{feff}').unwrap_or(text);
+
+    let mut normal = String::with_capacity(text.len());
+    for line in text.lines() {
+        normal.push_str(line.trim_end());
+        normal.push('\n');
+    }
+
+    let length = normal.trim_end().len();
+    normal.truncate(length);
+    if !normal.is_empty() {
+        normal.push('\n');
+    }
+
+    normal
+}
+
+/// The directory puzzle inputs live in.
+///
+/// This is the directory named by [INPUT_DIR_VAR] if it's set,
+/// and [DEFAULT_INPUT_DIR] otherwise.
+pub fn input_dir() -> PathBuf {
+    env::var_os(INPUT_DIR_VAR)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(DEFAULT_INPUT_DIR))
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::Stdin => write!(f, "standard input"),
+            InputSource::Path(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_locate() {
+        assert_eq!(
+            InputSource::locate(6, None, None),
+            InputSource::Path(PathBuf::from("src/input/day06.txt"))
+        );
+        assert_eq!(
+            InputSource::locate(12, None, Some(Path::new("/tmp/aoc"))),
+            InputSource::Path(PathBuf::from("/tmp/aoc/day12.txt"))
+        );
+        assert_eq!(
+            InputSource::locate(12, Some("mine.txt"), Some(Path::new("/tmp/aoc"))),
+            InputSource::Path(PathBuf::from("mine.txt"))
+        );
+        assert_eq!(InputSource::locate(1, Some("-"), None), InputSource::Stdin);
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(normalize("1 2\n3 4\n"), "1 2\n3 4\n");
+        assert_eq!(normalize("1 2\r\n3 4"), "1 2\n3 4\n");
+        assert_eq!(
+            normalize("\u{feff}1 2 \t\r\n\r\n3 4\r\n\r\n\n"),
+            "1 2\n\n3 4\n"
+        );
+        assert_eq!(normalize("  indented\n"), "  indented\n");
+        assert_eq!(normalize("\r\n\r\n"), "");
+    }
+
+    #[test]
+    fn test_read_missing_file() {
+        let input = InputSource::Path(PathBuf::from("src/input/day00-missing.txt"));
+        let err = input.read().unwrap_err();
+
+        assert!(err.to_string().contains("src/input/day00-missing.txt"));
+    }
+}
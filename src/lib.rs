@@ -1,17 +1,72 @@
 //! Helper systems for Advent of Code puzzles.
 
-use thiserror::Error;
-use std::io::Error as IOError;
-
-#[derive(Error, Debug)]
-pub enum AdventError {
-    #[error("Error reading file: {0}")]
-    /// An error reading the file used as input.
-    File(#[from] IOError),
-    /// An error in the format of the input file.
-    #[error("Parsing error: {0}")]
-    Parse(String),
-    /// The input has violated a contract.
-    #[error("Contract violation: {0}")]
-    Data(String),
+use std::fmt::Display;
+
+pub mod animate;
+pub mod answers;
+pub mod components;
+pub mod days;
+pub mod direction;
+pub mod error;
+pub mod grid;
+pub mod input;
+pub mod parse;
+pub mod point;
+pub mod search;
+pub mod timing;
+
+pub use error::{AdventError, Location};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// One of the two parts of a day's puzzle.
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    /// Check whether this part should be solved, given the
+    /// part that was asked for.
+    ///
+    /// Asking for [Option::None] selects both parts.
+    pub fn is_selected(self, selected: Option<Part>) -> bool {
+        selected.is_none_or(|part| part == self)
+    }
+}
+
+/// The solution to a single day's puzzle.
+///
+/// Every day follows the same shape: the input gets parsed once,
+/// and then each part of the puzzle is answered from that input.
+pub trait Solution {
+    /// The day of the month the puzzle was released.
+    const DAY: u8;
+    /// The title of the puzzle.
+    const TITLE: &'static str;
+
+    /// The puzzle input, after it has been parsed.
+    type Input;
+    /// The answer to part one.
+    type PartOne: Display;
+    /// The answer to part two.
+    ///
+    /// Days that haven't solved part two yet should use
+    /// [Infallible](std::convert::Infallible) here.
+    type PartTwo: Display;
+
+    /// Parse the puzzle input.
+    fn parse_input(text: &str) -> Result<Self::Input, AdventError>;
+
+    /// Solve part one of the puzzle.
+    ///
+    /// This fails if the input parsed fine, but still can't be solved.
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne, AdventError>;
+
+    /// Solve part two of the puzzle.
+    ///
+    /// This returns [Option::None] if part two hasn't been solved yet,
+    /// and fails like [Solution::part_one] does.
+    fn part_two(_input: &Self::Input) -> Result<Option<Self::PartTwo>, AdventError> {
+        Ok(None)
+    }
 }
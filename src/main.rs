@@ -1,10 +1,736 @@
 //! A mostly celebratory main application.
+//!
+//! Run without arguments (or with `stars`), it shows how many stars
+//! each day has earned. It also doubles as the way to run every
+//! day's solution:
+//!
+//! ```notrust
+//! advent2024 run <day> [--part 1|2] [--input <path>]
+//! ```
+//!
+//! Day 11 can also count its stones after any number of blinks:
+//!
+//! ```notrust
+//! advent2024 run 11 --blinks <count> [--rules <path>] [--input <path>]
+//! ```
+//!
+//! where the rules file gives a different set of rules for the stones
+//! to follow, as described by [day11::Rules::parse].
+//!
+//! Day 6 can draw the guard's patrol, optionally with an extra obstacle
+//! in their way:
+//!
+//! ```notrust
+//! advent2024 run 6 --render [--obstacle <column>,<row>] [--input <path>]
+//! ```
+//!
+//! Day 6's patrol and day 9's disk compaction can also be replayed one
+//! step at a time, either in the terminal or into a file of frames:
+//!
+//! ```notrust
+//! advent2024 run 6 --animate [--obstacle <column>,<row>] [--delay <ms> | --frames <path>] [--input <path>]
+//! advent2024 run 9 --animate [--part 1|2] [--delay <ms> | --frames <path>] [--input <path>]
+//! ```
+//!
+//! The input defaults to `src/input/dayXX.txt`, or to `dayXX.txt` inside
+//! the directory named by `ADVENT_INPUT_DIR`. Passing `--input -` reads
+//! the input from standard input.
+//!
+//! To make sure nothing has broken, every day can be checked against
+//! the answers stored in `answers.toml` in that same directory:
+//!
+//! ```notrust
+//! advent2024 verify [--answers <path>]
+//! ```
+//!
+//! And to find out which days are slow, each step of every day (or
+//! just one day) can be timed:
+//!
+//! ```notrust
+//! advent2024 bench [<day>] [--iterations <count>]
+//! ```
 
+use std::env;
+use std::fs::File;
+use std::io::{self, BufWriter};
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::Duration;
 
-/// The current number of solved stars
-/// generated by this repo.
-const STARS: u8 = 23;
+use advent2024::animate::{FrameDump, Recorder, Terminal};
+use advent2024::answers::{Answers, Verdict, ANSWERS_FILE};
+use advent2024::days::{self, day06, day09, day11, DAYS};
+use advent2024::input::{input_dir, InputSource};
+use advent2024::{AdventError, Part};
+use simple_grid::{Grid, GridIndex};
 
-fn main() {
-    println!("Number of stars found: {}", STARS);
+const USAGE: &str = "Usage: advent2024 [stars | run <day> [--part 1|2] [--input <path>] \
+                     | run 11 --blinks <count> [--rules <path>] [--input <path>] \
+                     | run 6 --render [--obstacle <column>,<row>] [--input <path>] \
+                     | run 6|9 --animate [--part 1|2] [--obstacle <column>,<row>] \
+                     [--delay <ms> | --frames <path>] [--input <path>] | verify [--answers <path>] | bench [<day>] [--iterations <count>]]";
+
+/// How many times each step is run when benchmarking, by default.
+const ITERATIONS: usize = 10;
+
+/// How long each frame of an animation is shown for, by default.
+const DELAY: Duration = Duration::from_millis(100);
+
+/// What the user asked this program to do.
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Command {
+    /// Celebrate the stars we've found so far.
+    Stars,
+    /// Solve a given day's puzzle.
+    Run {
+        day: u8,
+        part: Option<Part>,
+        input: Option<String>,
+    },
+    /// Count day 11's stones after some number of blinks.
+    Blinks {
+        count: usize,
+        rules: Option<String>,
+        input: Option<String>,
+    },
+    /// Draw day 6's patrol route.
+    Render {
+        obstacle: Option<(usize, usize)>,
+        input: Option<String>,
+    },
+    /// Replay day 6's patrol or day 9's compaction a step at a time,
+    /// in the terminal or into a file of frames.
+    Animate {
+        day: u8,
+        part: Option<Part>,
+        obstacle: Option<(usize, usize)>,
+        delay: Duration,
+        frames: Option<String>,
+        input: Option<String>,
+    },
+    /// Check every day against the accepted answers.
+    Verify { answers: Option<String> },
+    /// Time each step of a day's solution, or every day's.
+    Bench { day: Option<u8>, iterations: usize },
+}
+
+/// Figure out what to do from the command line arguments,
+/// not including the program name.
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    match args.next().as_deref() {
+        None | Some("stars") => Ok(Command::Stars),
+        Some("run") => {
+            let day = args.next().ok_or("Missing the day to run")?;
+            let day = day
+                .parse::<u8>()
+                .map_err(|_| format!("Invalid day {}", day))?;
+
+            let mut part = None;
+            let mut input = None;
+            let mut blinks = None;
+            let mut rules = None;
+            let mut render = false;
+            let mut obstacle = None;
+            let mut animate = false;
+            let mut delay = None;
+            let mut frames = None;
+            while let Some(arg) = args.next() {
+                match arg.as_str() {
+                    "--part" => {
+                        part = match args.next().as_deref() {
+                            Some("1") => Some(Part::One),
+                            Some("2") => Some(Part::Two),
+                            Some(other) => return Err(format!("Invalid part {}", other)),
+                            None => return Err("Missing the part to run".to_string()),
+                        }
+                    }
+                    "--input" => {
+                        input = Some(args.next().ok_or("Missing the input path")?);
+                    }
+                    "--blinks" => {
+                        let count = args.next().ok_or("Missing the blink count")?;
+                        blinks = Some(
+                            count
+                                .parse::<usize>()
+                                .map_err(|_| format!("Invalid blink count {}", count))?,
+                        );
+                    }
+                    "--rules" => {
+                        rules = Some(args.next().ok_or("Missing the rules path")?);
+                    }
+                    "--render" => render = true,
+                    "--obstacle" => {
+                        let place = args.next().ok_or("Missing the obstacle position")?;
+                        obstacle = Some(
+                            place
+                                .split_once(',')
+                                .and_then(|(column, row)| {
+                                    Some((column.parse().ok()?, row.parse().ok()?))
+                                })
+                                .ok_or_else(|| format!("Invalid obstacle position {}", place))?,
+                        );
+                    }
+                    "--animate" => animate = true,
+                    "--delay" => {
+                        let millis = args.next().ok_or("Missing the delay")?;
+                        delay = Some(Duration::from_millis(
+                            millis
+                                .parse()
+                                .map_err(|_| format!("Invalid delay {}", millis))?,
+                        ));
+                    }
+                    "--frames" => {
+                        frames = Some(args.next().ok_or("Missing the frames path")?);
+                    }
+                    other => return Err(format!("Unexpected argument {}", other)),
+                }
+            }
+
+            // Rule out every combination of flags that doesn't go
+            // together, before deciding what to do.
+            let drawing = render || animate;
+            let animation_flags = delay.is_some() || frames.is_some();
+            if blinks.is_some() {
+                if day != 11 {
+                    return Err("Only day 11 has blinks to count".to_string());
+                }
+                if part.is_some() {
+                    return Err("Blinks can't be counted for just one part".to_string());
+                }
+                if drawing || obstacle.is_some() || animation_flags {
+                    return Err("Only rules and input apply when counting blinks".to_string());
+                }
+            } else if rules.is_some() {
+                return Err("Rules only apply when counting blinks".to_string());
+            }
+            if render && animate {
+                return Err("Only one of rendering or animating can be done".to_string());
+            }
+            if render && day != 6 {
+                return Err("Only day 6 can be rendered".to_string());
+            }
+            if animate && day != 6 && day != 9 {
+                return Err("Only days 6 and 9 can be animated".to_string());
+            }
+            if drawing && day == 6 && part.is_some() {
+                return Err("Both parts are drawn together".to_string());
+            }
+            if obstacle.is_some() && !(drawing && day == 6) {
+                return Err("Obstacles only apply when rendering or animating day 6".to_string());
+            }
+            if animation_flags && !animate {
+                return Err("Delays and frames only apply when animating".to_string());
+            }
+            if delay.is_some() && frames.is_some() {
+                return Err("Delays only apply when animating in the terminal".to_string());
+            }
+
+            let command = if let Some(count) = blinks {
+                Command::Blinks {
+                    count,
+                    rules,
+                    input,
+                }
+            } else if animate {
+                Command::Animate {
+                    day,
+                    part,
+                    obstacle,
+                    delay: delay.unwrap_or(DELAY),
+                    frames,
+                    input,
+                }
+            } else if render {
+                Command::Render { obstacle, input }
+            } else {
+                Command::Run { day, part, input }
+            };
+            Ok(command)
+        }
+        Some("verify") => {
+            let mut answers = None;
+            while let Some(arg) = args.next() {
+                match arg.as_str() {
+                    "--answers" => {
+                        answers = Some(args.next().ok_or("Missing the answers path")?);
+                    }
+                    other => return Err(format!("Unexpected argument {}", other)),
+                }
+            }
+
+            Ok(Command::Verify { answers })
+        }
+        Some("bench") => {
+            let mut day = None;
+            let mut iterations = ITERATIONS;
+            while let Some(arg) = args.next() {
+                match arg.as_str() {
+                    "--iterations" => {
+                        let count = args.next().ok_or("Missing the iteration count")?;
+                        iterations = count
+                            .parse::<usize>()
+                            .ok()
+                            .filter(|&count| count > 0)
+                            .ok_or_else(|| format!("Invalid iteration count {}", count))?;
+                    }
+                    other if day.is_none() => {
+                        day = Some(
+                            other
+                                .parse::<u8>()
+                                .map_err(|_| format!("Invalid day {}", other))?,
+                        );
+                    }
+                    other => return Err(format!("Unexpected argument {}", other)),
+                }
+            }
+
+            Ok(Command::Bench { day, iterations })
+        }
+        Some(other) => Err(format!("Unknown command {}", other)),
+    }
+}
+
+fn run(command: Command) -> Result<ExitCode, AdventError> {
+    match command {
+        Command::Stars => print_stars(),
+        Command::Run { day, part, input } => {
+            let solution = days::find(day)
+                .ok_or_else(|| AdventError::Data(format!("Day {} has not been solved", day)))?;
+            let file = InputSource::resolve(day, input.as_deref()).read()?;
+
+            // Parsing errors point at the input, so show them with it.
+            let solved = match solution.solve(&file, part) {
+                Ok(solved) => solved,
+                Err(err) => {
+                    eprintln!("{}", err.render(&file));
+                    return Ok(ExitCode::FAILURE);
+                }
+            };
+
+            println!("Day {:02}: {}", solution.number, solution.title);
+            for (part, answer) in solved {
+                match answer {
+                    Some(answer) => println!("{}: {}", part_name(part), answer),
+                    None => println!("{} has not been solved yet", part_name(part)),
+                }
+            }
+        }
+        Command::Blinks {
+            count,
+            rules,
+            input,
+        } => {
+            let rules = match rules {
+                Some(path) => {
+                    let text = InputSource::Path(path.into()).read()?;
+                    match day11::Rules::parse(&text) {
+                        Ok(rules) => rules,
+                        Err(err) => {
+                            eprintln!("{}", err.render(&text));
+                            return Ok(ExitCode::FAILURE);
+                        }
+                    }
+                }
+                None => day11::Rules::default(),
+            };
+
+            let file = InputSource::resolve(11, input.as_deref()).read()?;
+            let stones = match day11::parse_input(&file) {
+                Ok(stones) => stones,
+                Err(err) => {
+                    eprintln!("{}", err.render(&file));
+                    return Ok(ExitCode::FAILURE);
+                }
+            };
+
+            let stones = day11::count_stones_with(&stones, count, &rules)?;
+            println!("After {} blinks there are {} stones", count, stones);
+        }
+        Command::Render { obstacle, input } => {
+            let file = InputSource::resolve(6, input.as_deref()).read()?;
+            let (grid, outcome, obstacle) = match patrol(&file, obstacle) {
+                Ok(patrol) => patrol,
+                Err(err) => {
+                    eprintln!("{}", err.render(&file));
+                    return Ok(ExitCode::FAILURE);
+                }
+            };
+
+            println!("{}", outcome.render(&grid, obstacle));
+            match outcome {
+                day06::Outcome::Exited(_) => println!("The guard leaves the map"),
+                day06::Outcome::Looped(..) => println!("The guard is stuck in a loop"),
+            }
+        }
+        Command::Animate {
+            day,
+            part,
+            obstacle,
+            delay,
+            frames,
+            input,
+        } => {
+            let file = InputSource::resolve(day, input.as_deref()).read()?;
+            let parsed = match day {
+                6 => patrol(&file, obstacle)
+                    .map(|(grid, outcome, obstacle)| Animation::Patrol(grid, outcome, obstacle)),
+                _ => day09::parse_input(&file).map(|disk| Animation::Disk(disk, part)),
+            };
+            let animation = match parsed {
+                Ok(animation) => animation,
+                Err(err) => {
+                    eprintln!("{}", err.render(&file));
+                    return Ok(ExitCode::FAILURE);
+                }
+            };
+
+            match frames {
+                Some(path) => {
+                    let written = File::create(&path).and_then(|out| {
+                        let mut dump = FrameDump::new(BufWriter::new(out));
+                        animation.play(&mut dump);
+                        dump.finish()
+                    });
+                    let count = written.map_err(|error| AdventError::Write {
+                        output: path.clone(),
+                        error,
+                    })?;
+                    println!("Wrote {} frames to {}", count, path);
+                }
+                None => {
+                    let mut terminal = Terminal::new(io::stdout(), delay);
+                    animation.play(&mut terminal);
+                    terminal.finish().map_err(|error| AdventError::Write {
+                        output: "the terminal".to_string(),
+                        error,
+                    })?;
+                }
+            }
+        }
+        Command::Verify { answers } => {
+            let path = answers
+                .map(PathBuf::from)
+                .unwrap_or_else(|| input_dir().join(ANSWERS_FILE));
+            let text = InputSource::Path(path).read()?;
+            let answers = match Answers::parse(&text) {
+                Ok(answers) => answers,
+                Err(err) => {
+                    eprintln!("{}", err.render(&text));
+                    return Ok(ExitCode::FAILURE);
+                }
+            };
+
+            if !verify(&answers) {
+                return Ok(ExitCode::FAILURE);
+            }
+        }
+        Command::Bench { day, iterations } => {
+            let days = match day {
+                Some(day) => vec![days::find(day).ok_or_else(|| {
+                    AdventError::Data(format!("Day {} has not been solved", day))
+                })?],
+                None => DAYS.iter().collect(),
+            };
+
+            for day in days {
+                println!("Day {:02}: {}", day.number, day.title);
+                let timings = InputSource::resolve(day.number, None)
+                    .read()
+                    .and_then(|file| day.time(&file, iterations));
+                match timings {
+                    Ok(timings) => println!("{}\n", timings),
+                    Err(err) => println!("error: {}\n", err),
+                }
+            }
+        }
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Follow the guard's patrol on day 6's map in `file`, with an
+/// extra obstacle at `obstacle` if there is one.
+fn patrol(
+    file: &str,
+    obstacle: Option<(usize, usize)>,
+) -> Result<(Grid<bool>, day06::Outcome, Option<GridIndex>), AdventError> {
+    let (guard, grid) = day06::parse_input(file)?;
+
+    let obstacle = obstacle.map(|(column, row)| GridIndex::new(column, row));
+    match obstacle.map(|obstacle| grid.get(obstacle)) {
+        Some(None) => return Err(AdventError::Data("The obstacle is off the map".to_string())),
+        Some(Some(true)) => {
+            return Err(AdventError::Data(
+                "There is already an obstacle there".to_string(),
+            ))
+        }
+        Some(Some(false)) if obstacle == Some(guard.position()) => {
+            return Err(AdventError::Data(
+                "The obstacle can't go where the guard starts".to_string(),
+            ))
+        }
+        _ => {}
+    }
+
+    let outcome = day06::simulate(&guard, &grid, obstacle);
+    Ok((grid, outcome, obstacle))
+}
+
+/// A simulation ready to be replayed.
+enum Animation {
+    /// Day 6's guard walking their patrol.
+    Patrol(Grid<bool>, day06::Outcome, Option<GridIndex>),
+    /// Day 9's disk being compacted, for one part or both in turn.
+    Disk(Vec<day09::Space>, Option<Part>),
+}
+
+impl Animation {
+    fn play(&self, recorder: &mut impl Recorder) {
+        match self {
+            Animation::Patrol(grid, outcome, obstacle) => {
+                outcome.animate(grid, *obstacle, recorder)
+            }
+            Animation::Disk(disk, selected) => {
+                for part in [Part::One, Part::Two] {
+                    if part.is_selected(*selected) {
+                        day09::animate(disk, part, recorder);
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn part_name(part: Part) -> &'static str {
+    match part {
+        Part::One => "Part one",
+        Part::Two => "Part two",
+    }
+}
+
+/// Run every day against its real input, and check the answers
+/// against the accepted ones.
+///
+/// This returns whether every answer that could be checked matched.
+fn verify(answers: &Answers) -> bool {
+    let mut verified = 0;
+    let mut failed = 0;
+
+    println!("Day  Part  Result");
+    for day in &DAYS {
+        let solved = InputSource::resolve(day.number, None)
+            .read()
+            .and_then(|file| day.solve(&file, None));
+        let solved = match solved {
+            Ok(solved) => solved,
+            Err(err) => {
+                println!("{:>3}     -  error: {}", day.number, err);
+                continue;
+            }
+        };
+
+        for (part, answer) in solved {
+            let verdict = answers.check(day.number, part, answer.as_deref());
+            match verdict {
+                Verdict::Pass => verified += 1,
+                Verdict::Fail { .. } => failed += 1,
+                Verdict::Missing | Verdict::Unsolved => {}
+            }
+
+            let part = match part {
+                Part::One => 1,
+                Part::Two => 2,
+            };
+            println!("{:>3}  {:>4}  {}", day.number, part, verdict);
+        }
+    }
+    println!("Number of stars verified: {}", verified);
+
+    if failed > 0 {
+        eprintln!("{} answers did not match", failed);
+    }
+    failed == 0
+}
+
+/// Print a table of the stars earned by each day, and the total.
+fn print_stars() {
+    println!("Day  Stars  Title");
+    for day in &DAYS {
+        println!(
+            "{:>3}  {:<5}  {}",
+            day.number,
+            "*".repeat(day.stars()),
+            day.title
+        );
+    }
+
+    let total: usize = DAYS.iter().map(|day| day.stars()).sum();
+    println!("Number of stars found: {}", total);
+}
+
+fn main() -> ExitCode {
+    let command = match parse_args(env::args().skip(1)) {
+        Ok(command) => command,
+        Err(message) => {
+            eprintln!("{}\n{}", message, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    match run(command) {
+        Ok(code) => code,
+        Err(err) => {
+            eprintln!("{}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn args(line: &str) -> impl Iterator<Item = String> + '_ {
+        line.split_whitespace().map(str::to_string)
+    }
+
+    #[test]
+    fn test_parse_args() {
+        assert_eq!(parse_args(args("")), Ok(Command::Stars));
+        assert_eq!(parse_args(args("stars")), Ok(Command::Stars));
+        assert_eq!(
+            parse_args(args("run 6")),
+            Ok(Command::Run {
+                day: 6,
+                part: None,
+                input: None
+            })
+        );
+        assert_eq!(
+            parse_args(args("run 13 --part 2")),
+            Ok(Command::Run {
+                day: 13,
+                part: Some(Part::Two),
+                input: None
+            })
+        );
+        assert_eq!(
+            parse_args(args("run 1 --input - --part 1")),
+            Ok(Command::Run {
+                day: 1,
+                part: Some(Part::One),
+                input: Some("-".to_string())
+            })
+        );
+        assert!(parse_args(args("run")).is_err());
+        assert!(parse_args(args("run 6 --part 3")).is_err());
+        assert!(parse_args(args("run 6 --input")).is_err());
+        assert_eq!(
+            parse_args(args("run 11 --blinks 100")),
+            Ok(Command::Blinks {
+                count: 100,
+                rules: None,
+                input: None
+            })
+        );
+        assert_eq!(
+            parse_args(args("run 11 --blinks 5 --rules mine.txt")),
+            Ok(Command::Blinks {
+                count: 5,
+                rules: Some("mine.txt".to_string()),
+                input: None
+            })
+        );
+        assert!(parse_args(args("run 11 --rules mine.txt")).is_err());
+        assert!(parse_args(args("run 11 --blinks 5 --render")).is_err());
+        assert!(parse_args(args("run 11 --blinks 5 --animate")).is_err());
+        assert!(parse_args(args("run 11 --blinks 5 --obstacle 1,1")).is_err());
+        assert!(parse_args(args("run 11 --blinks 5 --delay 10")).is_err());
+        assert!(parse_args(args("run 11 --blinks 5 --frames out.txt")).is_err());
+        assert_eq!(
+            parse_args(args("run 6 --render --obstacle 3,6")),
+            Ok(Command::Render {
+                obstacle: Some((3, 6)),
+                input: None
+            })
+        );
+        assert!(parse_args(args("run 7 --render")).is_err());
+        assert!(parse_args(args("run 6 --obstacle 3,6")).is_err());
+        assert!(parse_args(args("run 6 --render --obstacle 3")).is_err());
+        assert_eq!(
+            parse_args(args("run 6 --animate --obstacle 3,6 --delay 50")),
+            Ok(Command::Animate {
+                day: 6,
+                part: None,
+                obstacle: Some((3, 6)),
+                delay: Duration::from_millis(50),
+                frames: None,
+                input: None
+            })
+        );
+        assert_eq!(
+            parse_args(args("run 9 --animate --part 2 --frames disk.txt")),
+            Ok(Command::Animate {
+                day: 9,
+                part: Some(Part::Two),
+                obstacle: None,
+                delay: DELAY,
+                frames: Some("disk.txt".to_string()),
+                input: None
+            })
+        );
+        assert!(parse_args(args("run 7 --animate")).is_err());
+        assert!(parse_args(args("run 6 --animate --part 1")).is_err());
+        assert!(parse_args(args("run 9 --animate --obstacle 3,6")).is_err());
+        assert!(parse_args(args("run 6 --animate --render")).is_err());
+        assert!(parse_args(args("run 6 --animate --delay 5 --frames out.txt")).is_err());
+        assert!(parse_args(args("run 6 --animate --delay soon")).is_err());
+        assert!(parse_args(args("run 9 --frames out.txt")).is_err());
+        assert!(parse_args(args("run 6 --render --delay 10")).is_err());
+        assert!(parse_args(args("run 6 --render --frames out.txt")).is_err());
+        assert!(parse_args(args("run 6 --render --rules mine.txt")).is_err());
+        assert!(parse_args(args("run 9 --render --animate")).is_err());
+        assert!(parse_args(args("run 6 --delay 10 --frames out.txt")).is_err());
+        assert!(parse_args(args("run 6 --blinks 100")).is_err());
+        assert!(parse_args(args("run 11 --blinks 100 --part 2")).is_err());
+        assert!(parse_args(args("run 11 --blinks many")).is_err());
+        assert_eq!(
+            parse_args(args("verify")),
+            Ok(Command::Verify { answers: None })
+        );
+        assert_eq!(
+            parse_args(args("verify --answers mine.toml")),
+            Ok(Command::Verify {
+                answers: Some("mine.toml".to_string())
+            })
+        );
+        assert!(parse_args(args("verify --part 1")).is_err());
+        assert_eq!(
+            parse_args(args("bench")),
+            Ok(Command::Bench {
+                day: None,
+                iterations: ITERATIONS
+            })
+        );
+        assert_eq!(
+            parse_args(args("bench 6 --iterations 3")),
+            Ok(Command::Bench {
+                day: Some(6),
+                iterations: 3
+            })
+        );
+        assert!(parse_args(args("bench --iterations 0")).is_err());
+        assert!(parse_args(args("bench 6 7")).is_err());
+        assert!(parse_args(args("fly 6")).is_err());
+    }
+
+    #[test]
+    fn test_patrol() {
+        let map = "..#\n...\n.^.\n";
+
+        assert!(patrol(map, None).is_ok());
+        assert!(patrol(map, Some((0, 0))).is_ok());
+        assert!(patrol(map, Some((2, 0))).is_err());
+        assert!(patrol(map, Some((3, 0))).is_err());
+        assert!(patrol(map, Some((1, 2))).is_err());
+    }
 }
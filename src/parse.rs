@@ -0,0 +1,268 @@
+//! Reusable pieces for parsing puzzle input.
+//!
+//! Every helper takes the whole input `text` alongside the slice of
+//! it being parsed, so that errors can point at exactly where in the
+//! input things went wrong. The slices passed in should always come
+//! from splitting up `text` itself.
+
+use std::num::ParseIntError;
+use std::str::FromStr;
+use std::sync::LazyLock;
+
+use regex::Regex;
+use simple_grid::{Grid, GridIndex};
+
+use crate::AdventError;
+
+/// A line with nothing but whitespace on it, along with the line
+/// endings either side.
+static BLANK_LINE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\r?\n[ \t]*\r?\n").expect("Regex should be valid"));
+
+/// Parse `snippet`, a slice of `text`, as a number.
+pub fn number<T>(text: &str, snippet: &str) -> Result<T, AdventError>
+where
+    T: FromStr<Err = ParseIntError>,
+{
+    snippet
+        .parse::<T>()
+        .map_err(|err| AdventError::number(text, snippet, err))
+}
+
+/// Parse a list of numbers separated by whitespace, like `7 6 4 2 1`.
+pub fn numbers<T>(text: &str, line: &str) -> Result<Vec<T>, AdventError>
+where
+    T: FromStr<Err = ParseIntError>,
+{
+    line.split_whitespace()
+        .map(|num| number(text, num))
+        .collect()
+}
+
+/// Parse a list of numbers split up by `separator`, like `75,47,61`.
+pub fn separated<T>(text: &str, line: &str, separator: &str) -> Result<Vec<T>, AdventError>
+where
+    T: FromStr<Err = ParseIntError>,
+{
+    line.split(separator)
+        .map(|num| number(text, num.trim()))
+        .collect()
+}
+
+/// Parse a pair of numbers split up by `separator`, like `47|53`.
+pub fn pair<T>(text: &str, line: &str, separator: &str) -> Result<(T, T), AdventError>
+where
+    T: FromStr<Err = ParseIntError>,
+{
+    let (one, two) = line.split_once(separator).ok_or_else(|| {
+        AdventError::syntax(text, line, format!("a pair split by `{}`", separator))
+    })?;
+
+    Ok((number(text, one.trim())?, number(text, two.trim())?))
+}
+
+/// Parse every non-blank line of `text` with `parse`.
+pub fn lines<'a, T>(
+    text: &'a str,
+    parse: impl FnMut(&'a str) -> Result<T, AdventError>,
+) -> Result<Vec<T>, AdventError> {
+    text.lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(parse)
+        .collect()
+}
+
+/// Split `text` up into sections separated by blank lines.
+///
+/// Lines may end in `\r\n` as well as `\n`, and blank lines
+/// may still have spaces or tabs on them.
+pub fn sections(text: &str) -> impl Iterator<Item = &str> {
+    BLANK_LINE
+        .split(text)
+        .filter(|section| !section.trim().is_empty())
+}
+
+/// Match `re` against `snippet`, a slice of `text`, and parse every
+/// capture group as a number.
+///
+/// `expected` describes what `snippet` should have looked like,
+/// for when `re` doesn't match at all.
+pub fn captures<T, const N: usize>(
+    text: &str,
+    re: &Regex,
+    snippet: &str,
+    expected: &str,
+) -> Result<[T; N], AdventError>
+where
+    T: FromStr<Err = ParseIntError>,
+{
+    let captures = re
+        .captures(snippet)
+        .ok_or_else(|| AdventError::syntax(text, snippet, expected))?;
+    let (_, groups) = captures.extract::<N>();
+
+    let numbers = groups
+        .into_iter()
+        .map(|group| number(text, group))
+        .collect::<Result<Vec<T>, _>>()?;
+
+    // `extract` already made sure there are exactly N groups.
+    Ok(numbers
+        .try_into()
+        .unwrap_or_else(|_| unreachable!("Wrong number of captures")))
+}
+
+/// Parse `text` as a rectangular grid, converting each character with `cell`.
+///
+/// `cell` is also given the index of the character, so it can take
+/// note of where any special markers are while the grid is built.
+/// If a character isn't valid, `cell` should describe what was
+/// expected there instead, and the error will point at it.
+///
+/// Blank lines are skipped, but every other line needs to be as
+/// long as the first one.
+pub fn grid<T, E>(
+    text: &str,
+    mut cell: impl FnMut(char, GridIndex) -> Result<T, E>,
+) -> Result<Grid<T>, AdventError>
+where
+    E: Into<String>,
+{
+    let mut width = None;
+    let mut height = 0;
+    let mut cells = Vec::new();
+
+    for line in text.lines().filter(|line| !line.is_empty()) {
+        let row = line
+            .char_indices()
+            .enumerate()
+            .map(|(column, (offset, ch))| {
+                cell(ch, GridIndex::new(column, height)).map_err(|expected| {
+                    let snippet = &line[offset..offset + ch.len_utf8()];
+                    AdventError::syntax(text, snippet, expected)
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let width = *width.get_or_insert(row.len());
+        if row.len() != width {
+            return Err(AdventError::syntax(
+                text,
+                line,
+                format!("a row {} cells wide", width),
+            ));
+        }
+
+        cells.extend(row);
+        height += 1;
+    }
+
+    Ok(Grid::new(width.unwrap_or(0), height, cells))
+}
+
+/// Parse `text` as a rectangular grid of characters.
+pub fn char_grid(text: &str) -> Result<Grid<char>, AdventError> {
+    grid(text, |ch, _| Ok::<_, String>(ch))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_numbers() {
+        let text = "7 6 4 2 1\n1 2 x 8 9";
+        let mut lines = text.lines();
+
+        assert_eq!(
+            numbers::<u32>(text, lines.next().unwrap()).unwrap(),
+            vec![7, 6, 4, 2, 1]
+        );
+
+        let err = numbers::<u32>(text, lines.next().unwrap()).unwrap_err();
+        let location = err.location().unwrap();
+        assert_eq!((location.line, location.column), (2, 5));
+    }
+
+    #[test]
+    fn test_separated() {
+        let text = "75,47,61";
+
+        assert_eq!(separated::<u32>(text, text, ",").unwrap(), vec![75, 47, 61]);
+        assert!(separated::<u32>(text, text, "|").is_err());
+    }
+
+    #[test]
+    fn test_pair() {
+        let text = "47|53\n97 13";
+        let mut lines = text.lines();
+
+        assert_eq!(
+            pair::<u32>(text, lines.next().unwrap(), "|").unwrap(),
+            (47, 53)
+        );
+
+        let err = pair::<u32>(text, lines.next().unwrap(), "|").unwrap_err();
+        assert!(matches!(err, AdventError::Syntax { .. }));
+    }
+
+    #[test]
+    fn test_lines_and_sections() {
+        let text = "1 2\n3 4\n\n5 6\n\n";
+        let sections = sections(text).collect::<Vec<_>>();
+
+        assert_eq!(sections, vec!["1 2\n3 4", "5 6"]);
+        assert_eq!(
+            super::sections("1 2\r\n3 4\r\n \r\n5 6\r\n").collect::<Vec<_>>(),
+            vec!["1 2\r\n3 4", "5 6\r\n"]
+        );
+        assert_eq!(
+            lines(sections[0], |line| numbers::<u32>(text, line)).unwrap(),
+            vec![vec![1, 2], vec![3, 4]]
+        );
+    }
+
+    #[test]
+    fn test_captures() {
+        let re = Regex::new(r"X\+(\d+), Y\+(\d+)").unwrap();
+        let text = "Button A: X+94, Y+34\nButton B: Z+22";
+        let mut lines = text.lines();
+
+        let [x, y] = captures::<i64, 2>(text, &re, lines.next().unwrap(), "a button").unwrap();
+        assert_eq!((x, y), (94, 34));
+
+        let err = captures::<i64, 2>(text, &re, lines.next().unwrap(), "a button").unwrap_err();
+        assert_eq!(err.location().map(|location| location.line), Some(2));
+    }
+
+    #[test]
+    fn test_grid() {
+        let text = "#.\n.^\n";
+        let mut guard = None;
+
+        let grid = grid(text, |ch, idx| match ch {
+            '.' => Ok(false),
+            '#' => Ok(true),
+            '^' => {
+                guard = Some(idx);
+                Ok(false)
+            }
+            _ => Err("one of `.`, `#` or `^`"),
+        })
+        .unwrap();
+
+        assert_eq!(grid.dimensions(), (2, 2));
+        assert!(grid[(0, 0)]);
+        assert_eq!(guard, Some(GridIndex::new(1, 1)));
+    }
+
+    #[test]
+    fn test_grid_errors() {
+        let err = char_grid("abc\nde\n").unwrap_err();
+        assert_eq!(err.location().map(|location| location.line), Some(2));
+
+        let err = grid("12\n3x", |ch, _| ch.to_digit(10).ok_or("a digit")).unwrap_err();
+        let location = err.location().unwrap();
+        assert_eq!((location.line, location.column), (2, 2));
+    }
+}
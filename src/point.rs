@@ -0,0 +1,90 @@
+//! Signed points, for geometry that can wander off a grid.
+//!
+//! A [GridIndex] can't be negative, so any arithmetic that might
+//! step off the top or left of a grid is done with a [Point]
+//! instead, and only turned back into a [GridIndex] at the end.
+
+use std::ops::{Add, Mul, Sub};
+
+use simple_grid::GridIndex;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// A point on an unbounded plane, or a vector between two of them.
+///
+/// `x` is the column and `y` is the row, as with a [GridIndex].
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub const fn new(x: i64, y: i64) -> Point {
+        Point { x, y }
+    }
+
+    /// The point at a grid index, if it fits.
+    pub fn from_index(idx: GridIndex) -> Option<Point> {
+        Some(Point {
+            x: idx.column().try_into().ok()?,
+            y: idx.row().try_into().ok()?,
+        })
+    }
+
+    /// The grid index at this point, or [Option::None] if
+    /// either coordinate is negative.
+    pub fn to_index(self) -> Option<GridIndex> {
+        Some(GridIndex::new(
+            self.x.try_into().ok()?,
+            self.y.try_into().ok()?,
+        ))
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Mul<i64> for Point {
+    type Output = Point;
+
+    fn mul(self, scale: i64) -> Point {
+        Point::new(self.x * scale, self.y * scale)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let one = Point::new(3, 4);
+        let two = Point::new(5, 2);
+
+        assert_eq!(one + two, Point::new(8, 6));
+        assert_eq!(one - two, Point::new(-2, 2));
+        assert_eq!((two - one) * 3, Point::new(6, -6));
+    }
+
+    #[test]
+    fn test_index_conversions() {
+        let idx = GridIndex::new(7, 2);
+
+        assert_eq!(Point::from_index(idx), Some(Point::new(7, 2)));
+        assert_eq!(Point::new(7, 2).to_index(), Some(idx));
+        assert_eq!(Point::new(-1, 2).to_index(), None);
+        assert_eq!(Point::new(1, -2).to_index(), None);
+    }
+}
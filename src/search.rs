@@ -0,0 +1,377 @@
+//! Searching through graphs, and grids in particular.
+//!
+//! Every search here takes a starting node and a function giving
+//! the neighbors of a node, so the graph never has to be built up
+//! front. For grids, [cardinal] and [compass] give the neighbors
+//! of a cell, and can be filtered down to just the passable ones.
+//!
+//! The breadth first, depth first and Dijkstra searches also take
+//! a callback, which is told about each node in the order they are
+//! visited and decides whether to keep going.
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+use simple_grid::{Grid, GridIndex};
+
+use crate::direction::{Compass8, Direction};
+use crate::grid::GridExt;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// What a search should do after visiting a node.
+pub enum Flow {
+    /// Keep searching.
+    Continue,
+    /// Stop searching, leaving the rest of the graph unvisited.
+    Stop,
+}
+
+#[derive(Clone, Debug)]
+/// Everything a search found, and how it got there.
+pub struct Explored<N> {
+    order: Vec<N>,
+    parents: HashMap<N, N>,
+    costs: HashMap<N, u64>,
+}
+
+impl<N: Clone + Eq + Hash> Explored<N> {
+    fn new(start: N) -> Self {
+        Explored {
+            order: Vec::new(),
+            parents: HashMap::new(),
+            costs: HashMap::from([(start, 0)]),
+        }
+    }
+
+    /// Every node visited, in the order they were visited.
+    pub fn visited(&self) -> &[N] {
+        &self.order
+    }
+
+    /// Whether a node was reached by the search.
+    pub fn contains(&self, node: &N) -> bool {
+        self.costs.contains_key(node)
+    }
+
+    /// The cost of reaching a node.
+    ///
+    /// For the unweighted searches this is the number of steps taken.
+    pub fn cost(&self, node: &N) -> Option<u64> {
+        self.costs.get(node).copied()
+    }
+
+    /// The path from the start to `goal`, including both ends.
+    pub fn path_to(&self, goal: &N) -> Option<Vec<N>> {
+        if !self.contains(goal) {
+            return None;
+        }
+
+        let mut path = vec![goal.clone()];
+        while let Some(parent) = self.parents.get(path.last()?) {
+            path.push(parent.clone());
+        }
+        path.reverse();
+
+        Some(path)
+    }
+}
+
+/// Search breadth first from `start`.
+///
+/// Nodes are visited in order of the number of steps taken to reach
+/// them, so [Explored::path_to] gives a shortest path.
+pub fn bfs<N, I>(
+    start: N,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut visit: impl FnMut(&N) -> Flow,
+) -> Explored<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut explored = Explored::new(start.clone());
+    let mut frontier = VecDeque::from([start]);
+
+    while let Some(node) = frontier.pop_front() {
+        explored.order.push(node.clone());
+        if visit(&node) == Flow::Stop {
+            break;
+        }
+
+        let cost = explored.costs[&node] + 1;
+        for next in neighbors(&node) {
+            if !explored.contains(&next) {
+                explored.costs.insert(next.clone(), cost);
+                explored.parents.insert(next.clone(), node.clone());
+                frontier.push_back(next);
+            }
+        }
+    }
+
+    explored
+}
+
+/// Search depth first from `start`.
+///
+/// Neighbors are explored in the order they are given, each as
+/// deeply as possible before moving on to the next.
+pub fn dfs<N, I>(
+    start: N,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut visit: impl FnMut(&N) -> Flow,
+) -> Explored<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+    I::IntoIter: DoubleEndedIterator,
+{
+    let mut explored = Explored::new(start.clone());
+    let mut stack = vec![(start, None)];
+    let mut seen = HashSet::new();
+
+    while let Some((node, parent)) = stack.pop() {
+        if !seen.insert(node.clone()) {
+            continue;
+        }
+
+        if let Some(parent) = parent {
+            let cost = explored.costs[&parent] + 1;
+            explored.costs.insert(node.clone(), cost);
+            explored.parents.insert(node.clone(), parent);
+        }
+
+        explored.order.push(node.clone());
+        if visit(&node) == Flow::Stop {
+            break;
+        }
+
+        // Pushed in reverse, so the first neighbor is popped first.
+        for next in neighbors(&node).into_iter().rev() {
+            if !seen.contains(&next) {
+                stack.push((next, Some(node.clone())));
+            }
+        }
+    }
+
+    explored
+}
+
+/// Search from `start` in order of the total cost to reach each node.
+///
+/// `neighbors` gives each neighbor along with the cost of stepping to it,
+/// and [Explored::path_to] gives a cheapest path.
+pub fn dijkstra<N, I>(
+    start: N,
+    neighbors: impl FnMut(&N) -> I,
+    visit: impl FnMut(&N, u64) -> Flow,
+) -> Explored<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = (N, u64)>,
+{
+    best_first(start, neighbors, |_| 0, visit)
+}
+
+/// Find a cheapest path from `start` to any node where `is_goal` holds.
+///
+/// `heuristic` must be consistent: it's zero at a goal, and it never
+/// drops by more than the cost of stepping from a node to a neighbor.
+/// Nodes are never revisited once they've been reached, so otherwise
+/// the path found may not be the cheapest. This gives the path,
+/// including both ends, along with its cost.
+pub fn astar<N, I>(
+    start: N,
+    neighbors: impl FnMut(&N) -> I,
+    heuristic: impl FnMut(&N) -> u64,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, u64)>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = (N, u64)>,
+{
+    let mut goal = None;
+    let explored = best_first(start, neighbors, heuristic, |node, _| {
+        if is_goal(node) {
+            goal = Some(node.clone());
+            Flow::Stop
+        } else {
+            Flow::Continue
+        }
+    });
+
+    let goal = goal?;
+    Some((explored.path_to(&goal)?, explored.cost(&goal)?))
+}
+
+/// Visit nodes in order of their cost plus the heuristic, which is
+/// Dijkstra's algorithm when the heuristic is always zero and A* otherwise.
+fn best_first<N, I>(
+    start: N,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> u64,
+    mut visit: impl FnMut(&N, u64) -> Flow,
+) -> Explored<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = (N, u64)>,
+{
+    let mut explored = Explored::new(start.clone());
+    let mut done = HashSet::new();
+
+    // Nodes aren't necessarily ordered, so the heap holds their
+    // position in `nodes` rather than the nodes themselves.
+    let mut nodes = vec![start.clone()];
+    let mut frontier = BinaryHeap::from([Reverse((heuristic(&start), 0, 0))]);
+
+    while let Some(Reverse((_, cost, id))) = frontier.pop() {
+        let node = nodes[id].clone();
+        if explored.costs[&node] < cost || !done.insert(node.clone()) {
+            continue;
+        }
+
+        explored.order.push(node.clone());
+        if visit(&node, cost) == Flow::Stop {
+            break;
+        }
+
+        for (next, step) in neighbors(&node) {
+            let next_cost = cost + step;
+            if explored.cost(&next).is_some_and(|known| known <= next_cost) {
+                continue;
+            }
+
+            explored.costs.insert(next.clone(), next_cost);
+            explored.parents.insert(next.clone(), node.clone());
+            frontier.push(Reverse((
+                next_cost + heuristic(&next),
+                next_cost,
+                nodes.len(),
+            )));
+            nodes.push(next);
+        }
+    }
+
+    explored
+}
+
+/// The cells north, east, south and west of `idx` that are on the grid.
+pub fn cardinal<T>(grid: &Grid<T>, idx: GridIndex) -> impl Iterator<Item = GridIndex> + '_ {
+    Direction::ALL
+        .into_iter()
+        .filter_map(move |dir| grid.step(idx, dir))
+}
+
+/// All eight cells around `idx` that are on the grid, diagonals included.
+pub fn compass<T>(grid: &Grid<T>, idx: GridIndex) -> impl Iterator<Item = GridIndex> + '_ {
+    Compass8::ALL
+        .into_iter()
+        .filter_map(move |dir| grid.step(idx, dir))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::parse;
+
+    static MAZE: &str = "\
+..#.
+.##.
+....
+#..#";
+
+    fn open(grid: &Grid<char>, idx: GridIndex) -> impl Iterator<Item = GridIndex> + '_ {
+        cardinal(grid, idx).filter(|&next| grid[next] == '.')
+    }
+
+    #[test]
+    fn test_bfs() {
+        let grid = parse::char_grid(MAZE).unwrap();
+        let start = GridIndex::new(0, 0);
+        let goal = GridIndex::new(3, 0);
+
+        let explored = bfs(start, |&idx| open(&grid, idx), |_| Flow::Continue);
+
+        assert_eq!(explored.visited().len(), 11);
+        assert_eq!(explored.cost(&goal), Some(7));
+        assert_eq!(explored.path_to(&goal).map(|path| path.len()), Some(8));
+        assert_eq!(explored.path_to(&GridIndex::new(0, 3)), None);
+    }
+
+    #[test]
+    fn test_bfs_stops() {
+        let grid = parse::char_grid(MAZE).unwrap();
+        let start = GridIndex::new(0, 0);
+
+        let mut visits = 0;
+        let explored = bfs(
+            start,
+            |&idx| open(&grid, idx),
+            |_| {
+                visits += 1;
+                if visits == 3 {
+                    Flow::Stop
+                } else {
+                    Flow::Continue
+                }
+            },
+        );
+
+        assert_eq!(explored.visited().len(), 3);
+    }
+
+    #[test]
+    fn test_dfs() {
+        let grid = parse::char_grid(MAZE).unwrap();
+        let start = GridIndex::new(0, 0);
+
+        let explored = dfs(
+            start,
+            |&idx| open(&grid, idx).collect::<Vec<_>>(),
+            |_| Flow::Continue,
+        );
+
+        assert_eq!(explored.visited().len(), 11);
+        // Heading east first runs into a wall, so it goes south.
+        assert_eq!(
+            explored.visited()[..3],
+            [start, (1, 0).into(), (0, 1).into()]
+        );
+    }
+
+    #[test]
+    fn test_dijkstra_and_astar() {
+        // Stepping onto a cell costs the digit in it.
+        let grid = parse::grid("131\n191\n111", |ch, _| ch.to_digit(10).ok_or("a digit")).unwrap();
+        let start = GridIndex::new(0, 0);
+        let goal = GridIndex::new(2, 2);
+        let costs = |&idx: &GridIndex| {
+            cardinal(&grid, idx)
+                .map(|next| (next, grid[next] as u64))
+                .collect::<Vec<_>>()
+        };
+
+        let explored = dijkstra(start, costs, |_, _| Flow::Continue);
+        assert_eq!(explored.cost(&goal), Some(4));
+        assert_eq!(explored.cost(&GridIndex::new(1, 1)), Some(10));
+
+        let manhattan =
+            |idx: &GridIndex| (goal.column() - idx.column() + goal.row() - idx.row()) as u64;
+        let (path, cost) = astar(start, costs, manhattan, |&idx| idx == goal).unwrap();
+        assert_eq!(cost, 4);
+        assert_eq!(path.first(), Some(&start));
+        assert_eq!(path.last(), Some(&goal));
+        assert!(!path.contains(&GridIndex::new(1, 0)));
+    }
+
+    #[test]
+    fn test_adjacency() {
+        let grid = Grid::new(3, 3, vec![(); 9]);
+
+        assert_eq!(cardinal(&grid, GridIndex::new(0, 0)).count(), 2);
+        assert_eq!(cardinal(&grid, GridIndex::new(1, 1)).count(), 4);
+        assert_eq!(compass(&grid, GridIndex::new(0, 0)).count(), 3);
+        assert_eq!(compass(&grid, GridIndex::new(1, 1)).count(), 8);
+    }
+}
@@ -0,0 +1,118 @@
+//! Timing how long each day's solution takes.
+//!
+//! Each step of a solution (parsing, part one, and part two) is timed
+//! separately over a number of runs, so that it's obvious which one
+//! is the slow part.
+
+use std::fmt;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use crate::{AdventError, Solution};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// Summary statistics for a number of timed runs.
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// Summarize a series of timed runs.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `samples` is empty, since there's nothing to summarize.
+    pub fn from_samples(mut samples: Vec<Duration>) -> Stats {
+        assert!(!samples.is_empty(), "No samples to summarize");
+        samples.sort();
+
+        Stats {
+            min: samples[0],
+            median: samples[samples.len() / 2],
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:>12.2?} {:>12.2?} {:>12.2?}",
+            self.min, self.median, self.max
+        )
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// How long each step of a solution took.
+pub struct Timings {
+    pub parse: Stats,
+    pub part_one: Stats,
+    /// This is [Option::None] if part two hasn't been solved.
+    pub part_two: Option<Stats>,
+}
+
+impl fmt::Display for Timings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{:<10} {:>12} {:>12} {:>12}",
+            "Step", "Min", "Median", "Max"
+        )?;
+        writeln!(f, "{:<10} {}", "parse", self.parse)?;
+        write!(f, "{:<10} {}", "part one", self.part_one)?;
+        match self.part_two {
+            Some(part_two) => write!(f, "\n{:<10} {}", "part two", part_two),
+            None => write!(f, "\n{:<10} {:>12}", "part two", "unsolved"),
+        }
+    }
+}
+
+/// Time `run` over `iterations` runs.
+fn sample<T>(iterations: usize, mut run: impl FnMut() -> T) -> Stats {
+    let samples = (0..iterations.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            black_box(run());
+            start.elapsed()
+        })
+        .collect();
+
+    Stats::from_samples(samples)
+}
+
+/// Time each step of the solution `S` against `file`.
+pub fn time<S: Solution>(file: &str, iterations: usize) -> Result<Timings, AdventError> {
+    // Solve once up front, so that bad input is reported instead of timed.
+    let input = S::parse_input(file)?;
+    S::part_one(&input)?;
+    let solved = S::part_two(&input)?.is_some();
+
+    let parse = sample(iterations, || S::parse_input(black_box(file)));
+    let part_one = sample(iterations, || S::part_one(black_box(&input)));
+    let part_two = solved.then(|| sample(iterations, || S::part_two(black_box(&input))));
+
+    Ok(Timings {
+        parse,
+        part_one,
+        part_two,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_from_samples() {
+        let samples = [5, 1, 4, 2, 3].map(Duration::from_millis).to_vec();
+        let stats = Stats::from_samples(samples);
+
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.max, Duration::from_millis(5));
+    }
+}